@@ -28,7 +28,7 @@ fn bench_chunker(c: &mut Criterion) {
             |b, data| {
                 b.iter(|| {
                     let mut chunker = Chunker::new(ChunkConfig::default());
-                    let (chunks, _) = chunker.push(Bytes::from(black_box(data.clone())));
+                    let chunks = chunker.push_buf(Bytes::from(black_box(data.clone())));
                     let _final = chunker.finish();
                     black_box(chunks.len())
                 });
@@ -43,7 +43,7 @@ fn bench_chunker(c: &mut Criterion) {
             |b, data| {
                 b.iter(|| {
                     let mut chunker = Chunker::new(ChunkConfig::default());
-                    let (chunks, _) = chunker.push(Bytes::from(black_box(data.clone())));
+                    let chunks = chunker.push_buf(Bytes::from(black_box(data.clone())));
                     let _final = chunker.finish();
                     black_box(chunks.len())
                 });
@@ -64,7 +64,7 @@ fn bench_configs(c: &mut Criterion) {
         let config = ChunkConfig::new(2 * 1024, 8 * 1024, 32 * 1024).unwrap();
         b.iter(|| {
             let mut chunker = Chunker::new(config);
-            let (chunks, _) = chunker.push(Bytes::from(black_box(data.clone())));
+            let chunks = chunker.push_buf(Bytes::from(black_box(data.clone())));
             let _final = chunker.finish();
             black_box(chunks.len())
         });
@@ -75,7 +75,7 @@ fn bench_configs(c: &mut Criterion) {
         let config = ChunkConfig::default();
         b.iter(|| {
             let mut chunker = Chunker::new(config);
-            let (chunks, _) = chunker.push(Bytes::from(black_box(data.clone())));
+            let chunks = chunker.push_buf(Bytes::from(black_box(data.clone())));
             let _final = chunker.finish();
             black_box(chunks.len())
         });
@@ -86,7 +86,7 @@ fn bench_configs(c: &mut Criterion) {
         let config = ChunkConfig::new(64 * 1024, 256 * 1024, 1024 * 1024).unwrap();
         b.iter(|| {
             let mut chunker = Chunker::new(config);
-            let (chunks, _) = chunker.push(Bytes::from(black_box(data.clone())));
+            let chunks = chunker.push_buf(Bytes::from(black_box(data.clone())));
             let _final = chunker.finish();
             black_box(chunks.len())
         });
@@ -97,7 +97,7 @@ fn bench_configs(c: &mut Criterion) {
         let config = ChunkConfig::default().with_hash_config(chunkrs::HashConfig::disabled());
         b.iter(|| {
             let mut chunker = Chunker::new(config);
-            let (chunks, _) = chunker.push(Bytes::from(black_box(data.clone())));
+            let chunks = chunker.push_buf(Bytes::from(black_box(data.clone())));
             let _final = chunker.finish();
             black_box(chunks.len())
         });
@@ -118,7 +118,7 @@ fn bench_keyed_cdc(c: &mut Criterion) {
         let config = ChunkConfig::default().with_keyed_gear_table(Some(key));
         b.iter(|| {
             let mut chunker = Chunker::new(config);
-            let (chunks, _) = chunker.push(Bytes::from(black_box(data.clone())));
+            let chunks = chunker.push_buf(Bytes::from(black_box(data.clone())));
             let _final = chunker.finish();
             black_box(chunks.len())
         });
@@ -129,7 +129,7 @@ fn bench_keyed_cdc(c: &mut Criterion) {
         let config = ChunkConfig::default().with_keyed_gear_table(None);
         b.iter(|| {
             let mut chunker = Chunker::new(config);
-            let (chunks, _) = chunker.push(Bytes::from(black_box(data.clone())));
+            let chunks = chunker.push_buf(Bytes::from(black_box(data.clone())));
             let _final = chunker.finish();
             black_box(chunks.len())
         });
@@ -151,7 +151,7 @@ fn bench_streaming(c: &mut Criterion) {
             let batch_size = 8192;
 
             for chunk in black_box(&data).chunks(batch_size) {
-                let (chunks, _) = chunker.push(Bytes::copy_from_slice(chunk));
+                let chunks = chunker.push_buf(Bytes::copy_from_slice(chunk));
                 total += chunks.len();
             }
 
@@ -166,7 +166,7 @@ fn bench_streaming(c: &mut Criterion) {
     group.bench_function("single_push", |b| {
         b.iter(|| {
             let mut chunker = Chunker::new(ChunkConfig::default());
-            let (chunks, _) = chunker.push(Bytes::from(black_box(data.clone())));
+            let chunks = chunker.push_buf(Bytes::from(black_box(data.clone())));
             let _final = chunker.finish();
             black_box(chunks.len())
         });
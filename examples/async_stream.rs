@@ -1,14 +1,14 @@
 //! Async streaming chunking example.
 //!
 //! Demonstrates using the streaming API in an async context.
-//! The Chunker itself is synchronous (no async required), but can be
+//! The ChunkSession itself is synchronous (no async required), but can be
 //! used from async code as needed.
 //!
 //! Run with:
 //!     cargo run --example async_stream
 
 use bytes::Bytes;
-use chunkrs::{ChunkConfig, Chunker};
+use chunkrs::{ChunkConfig, ChunkSession};
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
 #[tokio::main]
@@ -28,11 +28,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     )
     .expect("invalid config");
 
-    let mut chunker = Chunker::new(config);
+    let mut session = ChunkSession::new(config);
 
     let mut total_chunks = 0;
     let mut total_bytes = 0;
-    let mut pending = Bytes::new();
 
     // Simulate async data streaming in batches
     let batch_size = 8192;
@@ -47,18 +46,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         println!("Async received batch: {} bytes", batch.len());
 
-        // Chunker.push() is synchronous - just call it
-        // Combine pending with new batch
-        let input = if pending.is_empty() {
-            batch
-        } else {
-            let mut combined = Vec::with_capacity(pending.len() + batch.len());
-            combined.extend_from_slice(&pending);
-            combined.extend_from_slice(&batch);
-            Bytes::from(combined)
-        };
-
-        let (chunks, leftover) = chunker.push(input);
+        // ChunkSession::push() is synchronous - just call it
+        let chunks = session.push(batch);
 
         for chunk in chunks {
             total_chunks += 1;
@@ -72,12 +61,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             );
         }
 
-        pending = leftover;
         offset = end;
     }
 
     // Finalize stream
-    if let Some(final_chunk) = chunker.finish() {
+    let (final_chunk, _summary) = session.finish();
+    if let Some(final_chunk) = final_chunk {
         total_chunks += 1;
         total_bytes += final_chunk.len();
 
@@ -90,8 +79,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     println!("\nTotal: {} chunks, {} bytes", total_chunks, total_bytes);
-    if total_chunks > 0 {
-        println!("Average chunk size: {} bytes", total_bytes / total_chunks);
+    if let Some(avg) = total_bytes.checked_div(total_chunks) {
+        println!("Average chunk size: {} bytes", avg);
     }
 
     Ok(())
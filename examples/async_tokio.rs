@@ -7,7 +7,7 @@
 //!     cargo run --example async_tokio
 
 use bytes::Bytes;
-use chunkrs::{ChunkConfig, Chunker};
+use chunkrs::{ChunkConfig, ChunkSession};
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
 #[tokio::main]
@@ -31,7 +31,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .into_iter()
         .enumerate()
         .map(|(stream_id, data)| {
-            let config = config;
             tokio::task::spawn_blocking(move || process_stream(stream_id, data, config))
         })
         .collect();
@@ -53,10 +52,9 @@ fn process_stream(
     data: Vec<u8>,
     config: ChunkConfig,
 ) -> Result<(usize, usize, usize), String> {
-    let mut chunker = Chunker::new(config);
+    let mut session = ChunkSession::new(config);
     let mut chunk_count = 0;
     let mut total_bytes = 0;
-    let mut pending = Bytes::new();
 
     // Process in batches
     let batch_size = 8192;
@@ -66,29 +64,19 @@ fn process_stream(
         let end = (offset + batch_size).min(data.len());
         let batch = Bytes::copy_from_slice(&data[offset..end]);
 
-        // Combine pending with new batch
-        let input = if pending.is_empty() {
-            batch
-        } else {
-            let mut combined = Vec::with_capacity(pending.len() + batch.len());
-            combined.extend_from_slice(&pending);
-            combined.extend_from_slice(&batch);
-            Bytes::from(combined)
-        };
-
-        let (chunks, leftover) = chunker.push(input);
+        let chunks = session.push(batch);
 
         for chunk in chunks {
             chunk_count += 1;
             total_bytes += chunk.len();
         }
 
-        pending = leftover;
         offset = end;
     }
 
     // Finalize
-    if let Some(final_chunk) = chunker.finish() {
+    let (final_chunk, _summary) = session.finish();
+    if let Some(final_chunk) = final_chunk {
         chunk_count += 1;
         total_bytes += final_chunk.len();
     }
@@ -4,7 +4,7 @@
 //!     cargo run --example sync_basic
 
 use bytes::Bytes;
-use chunkrs::{ChunkConfig, Chunker};
+use chunkrs::{ChunkConfig, ChunkSession};
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -13,30 +13,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut data = vec![0u8; 1024 * 1024]; // 1 MB buffer
     rng.fill(data.as_mut_slice()); // Fill with random bytes
 
-    // Create chunker with default config
-    let mut chunker = Chunker::new(ChunkConfig::default());
+    // Create a chunking session with default config
+    let mut session = ChunkSession::new(ChunkConfig::default());
 
     println!("Chunking {} bytes of data...\n", data.len());
 
     let mut total_chunks = 0;
     let mut total_bytes = 0;
-    let mut pending = Bytes::new();
 
     // Simulate streaming data in batches
     let batch_size = 8 * 1024; // 8 KB batches
     for chunk in data.chunks(batch_size) {
         let batch = Bytes::copy_from_slice(chunk);
-        // Combine pending with new batch
-        let input = if pending.is_empty() {
-            batch
-        } else {
-            let mut combined = Vec::with_capacity(pending.len() + batch.len());
-            combined.extend_from_slice(&pending);
-            combined.extend_from_slice(&batch);
-            Bytes::from(combined)
-        };
-
-        let (chunks, leftover) = chunker.push(input);
+        let chunks = session.push(batch);
 
         for chunk_result in chunks {
             total_chunks += 1;
@@ -59,12 +48,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 );
             }
         }
-
-        pending = leftover;
     }
 
     // Finalize stream
-    if let Some(final_chunk) = chunker.finish() {
+    let (final_chunk, _summary) = session.finish();
+    if let Some(final_chunk) = final_chunk {
         total_chunks += 1;
         total_bytes += final_chunk.len();
 
@@ -87,8 +75,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     println!("\nTotal: {} chunks, {} bytes", total_chunks, total_bytes);
-    if total_chunks > 0 {
-        println!("Average chunk size: {} bytes", total_bytes / total_chunks);
+    if let Some(avg) = total_bytes.checked_div(total_chunks) {
+        println!("Average chunk size: {} bytes", avg);
     }
 
     Ok(())
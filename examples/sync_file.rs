@@ -7,7 +7,7 @@
 //!     cargo run --example sync_file
 
 use bytes::Bytes;
-use chunkrs::{ChunkConfig, Chunker};
+use chunkrs::{ChunkConfig, ChunkSession};
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -26,11 +26,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     )
     .expect("invalid config");
 
-    let mut chunker = Chunker::new(config);
+    let mut session = ChunkSession::new(config);
 
     let mut total_chunks = 0;
     let mut total_bytes = 0;
-    let mut pending = Bytes::new();
 
     // Simulate streaming data with variable batch sizes
     let batch_sizes = [1, 100, 1024, 8192, 16384, 32768];
@@ -46,17 +45,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         println!("Pushing batch: {} bytes", batch.len());
 
-        // Combine pending with new batch
-        let input = if pending.is_empty() {
-            batch
-        } else {
-            let mut combined = Vec::with_capacity(pending.len() + batch.len());
-            combined.extend_from_slice(&pending);
-            combined.extend_from_slice(&batch);
-            Bytes::from(combined)
-        };
-
-        let (chunks, leftover) = chunker.push(input);
+        let chunks = session.push(batch);
 
         for chunk in chunks {
             total_chunks += 1;
@@ -70,24 +59,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             );
         }
 
-        pending = leftover;
         offset = end;
     }
 
     // Handle any remaining data
     if offset < data.len() {
         let batch = Bytes::copy_from_slice(&data[offset..]);
-        // Combine pending with remaining data
-        let input = if pending.is_empty() {
-            batch
-        } else {
-            let mut combined = Vec::with_capacity(pending.len() + batch.len());
-            combined.extend_from_slice(&pending);
-            combined.extend_from_slice(&batch);
-            Bytes::from(combined)
-        };
-
-        let (chunks, _leftover) = chunker.push(input);
+        let chunks = session.push(batch);
         for chunk in chunks {
             total_chunks += 1;
             total_bytes += chunk.len();
@@ -98,11 +76,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 chunk.len()
             );
         }
-        // leftover is not needed since finish() will handle any pending data
     }
 
     // Finalize stream
-    if let Some(final_chunk) = chunker.finish() {
+    let (final_chunk, _summary) = session.finish();
+    if let Some(final_chunk) = final_chunk {
         total_chunks += 1;
         total_bytes += final_chunk.len();
 
@@ -115,8 +93,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     println!("\nTotal: {} chunks, {} bytes", total_chunks, total_bytes);
-    if total_chunks > 0 {
-        println!("Average chunk size: {} bytes", total_bytes / total_chunks);
+    if let Some(avg) = total_bytes.checked_div(total_chunks) {
+        println!("Average chunk size: {} bytes", avg);
     }
 
     Ok(())
@@ -45,7 +45,7 @@ fuzz_target!(|data: Vec<u8>| {
 /// Test basic chunking correctness for a single configuration
 fn test_chunking(data: &[u8], config: ChunkConfig) {
     let mut chunker = Chunker::new(config);
-    let (chunks, _) = chunker.push(Bytes::from(data.to_vec()));
+    let chunks = chunker.push_buf(Bytes::from(data.to_vec()));
     let final_chunk = chunker.finish();
     let all_chunks: Vec<_> = chunks.into_iter().chain(final_chunk).collect();
 
@@ -110,13 +110,13 @@ fn test_chunking(data: &[u8], config: ChunkConfig) {
 fn test_determinism(data: &[u8], config: ChunkConfig) {
     // First run
     let mut chunker1 = Chunker::new(config);
-    let (chunks1, _) = chunker1.push(Bytes::from(data.to_vec()));
+    let chunks1 = chunker1.push_buf(Bytes::from(data.to_vec()));
     let final1 = chunker1.finish();
     let all_chunks1: Vec<_> = chunks1.into_iter().chain(final1).collect();
 
     // Second run
     let mut chunker2 = Chunker::new(config);
-    let (chunks2, _) = chunker2.push(Bytes::from(data.to_vec()));
+    let chunks2 = chunker2.push_buf(Bytes::from(data.to_vec()));
     let final2 = chunker2.finish();
     let all_chunks2: Vec<_> = chunks2.into_iter().chain(final2).collect();
 
@@ -158,7 +158,7 @@ fn test_determinism(data: &[u8], config: ChunkConfig) {
 fn test_hashing(data: &[u8]) {
     let config_with_hash = ChunkConfig::default().with_hash_config(chunkrs::HashConfig::enabled());
     let mut chunker = Chunker::new(config_with_hash);
-    let (chunks, _) = chunker.push(Bytes::from(data.to_vec()));
+    let chunks = chunker.push_buf(Bytes::from(data.to_vec()));
     let final_chunk = chunker.finish();
     let all_chunks: Vec<_> = chunks.into_iter().chain(final_chunk).collect();
 
@@ -174,7 +174,7 @@ fn test_hashing(data: &[u8]) {
     // Verify: same content produces same hash
     if !data.is_empty() {
         let mut chunker2 = Chunker::new(config_with_hash);
-        let (chunks2, _) = chunker2.push(Bytes::from(data.to_vec()));
+        let chunks2 = chunker2.push_buf(Bytes::from(data.to_vec()));
         let final2 = chunker2.finish();
         let all_chunks2: Vec<_> = chunks2.into_iter().chain(final2).collect();
 
@@ -194,7 +194,7 @@ fn test_keyed_cdc(data: &[u8]) {
     let key = [0u8; 32];
     let config_with_key = ChunkConfig::default().with_key(key);
     let mut chunker = Chunker::new(config_with_key);
-    let (chunks, _) = chunker.push(Bytes::from(data.to_vec()));
+    let chunks = chunker.push_buf(Bytes::from(data.to_vec()));
     let final_chunk = chunker.finish();
     let all_chunks: Vec<_> = chunks.into_iter().chain(final_chunk).collect();
 
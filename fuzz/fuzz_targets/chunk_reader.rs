@@ -0,0 +1,59 @@
+#![no_main]
+
+//! Fuzz target for the `ChunkReader` adapter.
+//!
+//! Tests:
+//! - Read-size independence: short reads of any size give the same chunks
+//! - Agreement with a single `push_buf()` + `finish()`
+//! - Offset continuity and data integrity
+
+use std::io::{self, Read};
+
+use bytes::Bytes;
+use chunkrs::{ChunkConfig, ChunkReader, Chunker};
+use libfuzzer_sys::fuzz_target;
+
+/// A reader that returns at most `step` bytes per call.
+struct ShortReader<'a> {
+    data: &'a [u8],
+    step: usize,
+}
+
+impl Read for ShortReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.step.min(buf.len()).min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+fuzz_target!(|input: &[u8]| {
+    let Some((&header, data)) = input.split_first() else {
+        return;
+    };
+    // Low nibble picks the read size, high nibble the buffer capacity.
+    let step = usize::from(header & 0x0F) * 37 + 1;
+    let capacity = usize::from(header >> 4) * 61 + 1;
+    let config = ChunkConfig::new(4, 16, 64).unwrap();
+
+    let mut chunker = Chunker::new(config);
+    let chunks = chunker.push_buf(Bytes::copy_from_slice(data));
+    let expected: Vec<_> = chunks.into_iter().chain(chunker.finish()).collect();
+
+    let reader = ChunkReader::with_capacity(capacity, ShortReader { data, step }, config);
+    let actual: Vec<_> = reader
+        .collect::<Result<_, _>>()
+        .expect("in-memory reads never fail");
+
+    assert_eq!(actual.len(), expected.len(), "chunk count depends on read size");
+
+    let mut offset = 0u64;
+    for (a, e) in actual.iter().zip(&expected) {
+        assert_eq!(a.offset, Some(offset), "offsets must be contiguous");
+        assert_eq!(a.data, e.data, "chunk data depends on read size");
+        assert_eq!(a.hash, e.hash, "chunk hash depends on read size");
+        offset += a.len() as u64;
+    }
+    assert_eq!(offset, data.len() as u64, "chunks must cover the input");
+});
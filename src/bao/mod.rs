@@ -0,0 +1,8 @@
+//! BLAKE3/Bao verified streaming of chunk data.
+//!
+//! - [`BaoOutboard`] - Bao outboard tree for one chunk, verifies slices against its `ChunkHash`
+
+mod outboard;
+
+// Re-export for use within the crate (module is private, so pub is crate-local)
+pub use outboard::BaoOutboard;
@@ -0,0 +1,529 @@
+//! Bao outboard encoding and slice verification.
+
+use blake3::hazmat::{
+    ChainingValue, HasherExt, Mode, left_subtree_len, merge_subtrees_non_root, merge_subtrees_root,
+};
+use blake3::{CHUNK_LEN, Hasher};
+
+use crate::chunk::{Chunk, ChunkHash};
+use crate::error::ChunkError;
+use crate::hash::HashAlgorithm;
+
+/// Size of the little-endian content length header.
+const HEADER_LEN: usize = 8;
+
+/// Size of one parent node (left and right chaining values).
+const PARENT_LEN: usize = 64;
+
+/// A Bao outboard tree for the data of one chunk.
+///
+/// The outboard holds the interior nodes of the chunk's BLAKE3 hash tree, but
+/// not the data itself. Its root is exactly the chunk's BLAKE3 hash, so a
+/// receiver that already trusts [`Chunk::hash`] can verify slices of the data
+/// as they arrive, before the whole chunk is downloaded.
+///
+/// # Format
+///
+/// Matches the `bao` outboard format: an 8-byte little-endian content length
+/// followed by the parent nodes of the BLAKE3 tree in pre-order, each as the
+/// 32-byte left and right chaining values. The outboard of `n` bytes holds
+/// `max(1, ceil(n / 1024)) - 1` parent nodes.
+///
+/// # Slices
+///
+/// Slices are verified in units of BLAKE3 chunks (1024 bytes): a slice must
+/// start on a multiple of 1024 and end on a multiple of 1024 or at the end of
+/// the data.
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::{BaoOutboard, Chunk};
+///
+/// let data = vec![0xA5u8; 5000];
+/// let (hash, outboard) = BaoOutboard::encode(&data);
+/// let chunk = Chunk::new(data.clone()).set_hash(hash);
+///
+/// // The receiver has `chunk.hash` and the outboard; data arrives in pieces.
+/// let trusted = chunk.hash.unwrap();
+/// assert!(outboard.verify_slice(&trusted, 0, &data[..2048]));
+/// assert!(outboard.verify_slice(&trusted, 4096, &data[4096..]));
+/// assert!(!outboard.verify_slice(&trusted, 0, &[0u8; 1024]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaoOutboard {
+    encoded: Vec<u8>,
+}
+
+impl BaoOutboard {
+    /// Computes the BLAKE3 hash and outboard tree of `data` in one pass.
+    pub fn encode(data: &[u8]) -> (ChunkHash, Self) {
+        Self::encode_inner(data, None)
+    }
+
+    /// Computes the keyed BLAKE3 hash and outboard tree of `data`.
+    ///
+    /// The hash is tagged [`HashAlgorithm::Blake3Keyed`] and matches chunks
+    /// hashed with [`HashConfig::with_key`](crate::HashConfig::with_key).
+    pub fn encode_keyed(key: &[u8; 32], data: &[u8]) -> (ChunkHash, Self) {
+        Self::encode_inner(data, Some(key))
+    }
+
+    /// Computes the outboard tree for a chunk's data.
+    ///
+    /// Returns an error if the chunk has no hash, if its hash is not an
+    /// unkeyed BLAKE3 hash, or if the hash does not match the data: the
+    /// outboard could not verify anything against it.
+    pub fn for_chunk(chunk: &Chunk) -> Result<Self, ChunkError> {
+        Self::for_chunk_inner(chunk, None)
+    }
+
+    /// Computes the outboard tree for a chunk hashed with keyed BLAKE3.
+    ///
+    /// Returns an error if the chunk's hash is not a keyed BLAKE3 hash of its
+    /// data under `key`.
+    pub fn for_chunk_keyed(key: &[u8; 32], chunk: &Chunk) -> Result<Self, ChunkError> {
+        Self::for_chunk_inner(chunk, Some(key))
+    }
+
+    /// Parses an encoded outboard.
+    ///
+    /// Returns `None` if the size does not match the length header.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let header: [u8; HEADER_LEN] = bytes.get(..HEADER_LEN)?.try_into().ok()?;
+        let content_len = u64::from_le_bytes(header);
+        if (bytes.len() - HEADER_LEN) as u64 != parent_count(content_len).checked_mul(64)? {
+            return None;
+        }
+        Some(Self {
+            encoded: bytes.to_vec(),
+        })
+    }
+
+    /// Returns the encoded outboard.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.encoded
+    }
+
+    /// Returns the length of the data this outboard describes.
+    pub fn content_len(&self) -> u64 {
+        let mut header = [0u8; HEADER_LEN];
+        header.copy_from_slice(&self.encoded[..HEADER_LEN]);
+        u64::from_le_bytes(header)
+    }
+
+    /// Verifies `slice`, located at byte `start` of the data, against the
+    /// trusted BLAKE3 `root` (usually [`Chunk::hash`]).
+    ///
+    /// Checks every outboard node on the path from the root to the slice.
+    /// Returns `false` on any mismatch, on a misaligned range, or if `root` is
+    /// not an unkeyed BLAKE3 hash.
+    pub fn verify_slice(&self, root: &ChunkHash, start: u64, slice: &[u8]) -> bool {
+        root.algorithm() == HashAlgorithm::Blake3 && self.verify_inner(None, root, start, slice)
+    }
+
+    /// Verifies `slice` against a trusted keyed BLAKE3 `root`.
+    pub fn verify_slice_keyed(
+        &self,
+        key: &[u8; 32],
+        root: &ChunkHash,
+        start: u64,
+        slice: &[u8],
+    ) -> bool {
+        root.algorithm() == HashAlgorithm::Blake3Keyed
+            && self.verify_inner(Some(key), root, start, slice)
+    }
+
+    fn for_chunk_inner(chunk: &Chunk, key: Option<&[u8; 32]>) -> Result<Self, ChunkError> {
+        let expected = chunk.hash.ok_or(ChunkError::InvalidInput {
+            message: "chunk has no hash",
+        })?;
+        let (hash, outboard) = Self::encode_inner(&chunk.data, key);
+        if expected.algorithm() != hash.algorithm() {
+            return Err(ChunkError::InvalidInput {
+                message: "chunk hash algorithm has no Bao outboard",
+            });
+        }
+        if expected != hash {
+            return Err(ChunkError::InvalidInput {
+                message: "chunk hash does not match its data",
+            });
+        }
+        Ok(outboard)
+    }
+
+    fn encode_inner(data: &[u8], key: Option<&[u8; 32]>) -> (ChunkHash, Self) {
+        let mut encoded =
+            Vec::with_capacity(HEADER_LEN + parent_count(data.len() as u64) as usize * PARENT_LEN);
+        encoded.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        let root = encode_subtree(key, data, 0, true, &mut encoded);
+        let algorithm = match key {
+            Some(_) => HashAlgorithm::Blake3Keyed,
+            None => HashAlgorithm::Blake3,
+        };
+        (ChunkHash::with_algorithm(algorithm, root), Self { encoded })
+    }
+
+    fn verify_inner(
+        &self,
+        key: Option<&[u8; 32]>,
+        root: &ChunkHash,
+        start: u64,
+        slice: &[u8],
+    ) -> bool {
+        let content_len = self.content_len();
+        let Some(end) = start.checked_add(slice.len() as u64) else {
+            return false;
+        };
+        let aligned_end = end % CHUNK_LEN as u64 == 0 || end == content_len;
+        if end > content_len || start % CHUNK_LEN as u64 != 0 || !aligned_end {
+            return false;
+        }
+        if content_len == 0 {
+            return *root.as_bytes() == *new_hasher(key).finalize().as_bytes();
+        }
+        if slice.is_empty() {
+            return false;
+        }
+
+        let range = Range { start, slice };
+        let nodes = &self.encoded[HEADER_LEN..];
+        verify_subtree(key, nodes, 0, 0, content_len, true, root.as_bytes(), &range)
+    }
+}
+
+/// The slice being verified.
+struct Range<'a> {
+    start: u64,
+    slice: &'a [u8],
+}
+
+impl Range<'_> {
+    fn overlaps(&self, offset: u64, len: u64) -> bool {
+        self.start < offset + len && offset < self.start + self.slice.len() as u64
+    }
+}
+
+/// Number of parent nodes in a tree over `len` bytes.
+fn parent_count(len: u64) -> u64 {
+    len.div_ceil(CHUNK_LEN as u64).max(1) - 1
+}
+
+fn new_hasher(key: Option<&[u8; 32]>) -> Hasher {
+    match key {
+        Some(key) => Hasher::new_keyed(key),
+        None => Hasher::new(),
+    }
+}
+
+fn mode(key: Option<&[u8; 32]>) -> Mode<'_> {
+    match key {
+        Some(key) => Mode::KeyedHash(key),
+        None => Mode::Hash,
+    }
+}
+
+/// Hashes a single BLAKE3 chunk at `offset`.
+fn chunk_cv(key: Option<&[u8; 32]>, data: &[u8], offset: u64, is_root: bool) -> ChainingValue {
+    let mut hasher = new_hasher(key);
+    if is_root {
+        hasher.update(data);
+        return *hasher.finalize().as_bytes();
+    }
+    hasher.set_input_offset(offset).update(data);
+    hasher.finalize_non_root()
+}
+
+fn merge(
+    key: Option<&[u8; 32]>,
+    left: &ChainingValue,
+    right: &ChainingValue,
+    is_root: bool,
+) -> ChainingValue {
+    if is_root {
+        *merge_subtrees_root(left, right, mode(key)).as_bytes()
+    } else {
+        merge_subtrees_non_root(left, right, mode(key))
+    }
+}
+
+/// Appends the parent nodes of `data` (starting at `offset`) in pre-order and
+/// returns the subtree's chaining value (or root hash).
+fn encode_subtree(
+    key: Option<&[u8; 32]>,
+    data: &[u8],
+    offset: u64,
+    is_root: bool,
+    out: &mut Vec<u8>,
+) -> ChainingValue {
+    if data.len() <= CHUNK_LEN {
+        return chunk_cv(key, data, offset, is_root);
+    }
+    let node_pos = out.len();
+    out.extend_from_slice(&[0u8; PARENT_LEN]);
+
+    let split = left_subtree_len(data.len() as u64) as usize;
+    let left = encode_subtree(key, &data[..split], offset, false, out);
+    let right = encode_subtree(key, &data[split..], offset + split as u64, false, out);
+
+    out[node_pos..node_pos + 32].copy_from_slice(&left);
+    out[node_pos + 32..node_pos + PARENT_LEN].copy_from_slice(&right);
+    merge(key, &left, &right, is_root)
+}
+
+/// Checks the subtree at `offset`/`len`, whose parent node (if any) is the
+/// `node`-th entry of `nodes`, against `expected`.
+#[allow(clippy::too_many_arguments)]
+fn verify_subtree(
+    key: Option<&[u8; 32]>,
+    nodes: &[u8],
+    node: usize,
+    offset: u64,
+    len: u64,
+    is_root: bool,
+    expected: &ChainingValue,
+    range: &Range<'_>,
+) -> bool {
+    if len <= CHUNK_LEN as u64 {
+        let begin = (offset - range.start) as usize;
+        let data = &range.slice[begin..begin + len as usize];
+        return chunk_cv(key, data, offset, is_root) == *expected;
+    }
+
+    let Some(parent) = nodes.get(node * PARENT_LEN..(node + 1) * PARENT_LEN) else {
+        return false;
+    };
+    let mut left = [0u8; 32];
+    let mut right = [0u8; 32];
+    left.copy_from_slice(&parent[..32]);
+    right.copy_from_slice(&parent[32..]);
+    if merge(key, &left, &right, is_root) != *expected {
+        return false;
+    }
+
+    let split = left_subtree_len(len);
+    // Pre-order: the left subtree's nodes follow this one, then the right's.
+    let left_nodes = parent_count(split) as usize;
+    let left_ok = !range.overlaps(offset, split)
+        || verify_subtree(key, nodes, node + 1, offset, split, false, &left, range);
+    let right_ok = !range.overlaps(offset + split, len - split)
+        || verify_subtree(
+            key,
+            nodes,
+            node + 1 + left_nodes,
+            offset + split,
+            len - split,
+            false,
+            &right,
+            range,
+        );
+    left_ok && right_ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ChunkConfig, Chunker, HashConfig};
+
+    const SIZES: [usize; 12] = [
+        0, 1, 1023, 1024, 1025, 2048, 2049, 3072, 4096, 5000, 8193, 65536,
+    ];
+
+    fn data_of(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn test_root_matches_blake3() {
+        for len in SIZES {
+            let data = data_of(len);
+            let (hash, outboard) = BaoOutboard::encode(&data);
+            assert_eq!(
+                hash.as_bytes(),
+                blake3::hash(&data).as_bytes(),
+                "len {}",
+                len
+            );
+            assert_eq!(outboard.content_len(), len as u64);
+            assert_eq!(
+                outboard.as_bytes().len(),
+                HEADER_LEN + parent_count(len as u64) as usize * PARENT_LEN
+            );
+        }
+    }
+
+    #[test]
+    fn test_keyed_root_matches_blake3() {
+        let key = [9u8; 32];
+        for len in SIZES {
+            let data = data_of(len);
+            let (hash, outboard) = BaoOutboard::encode_keyed(&key, &data);
+            assert_eq!(hash.algorithm(), HashAlgorithm::Blake3Keyed);
+            assert_eq!(hash.as_bytes(), blake3::keyed_hash(&key, &data).as_bytes());
+            assert!(outboard.verify_slice_keyed(&key, &hash, 0, &data));
+            assert!(!outboard.verify_slice(&hash, 0, &data));
+        }
+    }
+
+    #[test]
+    fn test_every_chunk_slice_verifies() {
+        for len in SIZES {
+            let data = data_of(len);
+            let (hash, outboard) = BaoOutboard::encode(&data);
+            assert!(outboard.verify_slice(&hash, 0, &data), "whole, len {}", len);
+            for start in (0..len).step_by(CHUNK_LEN) {
+                let end = (start + CHUNK_LEN).min(len);
+                assert!(
+                    outboard.verify_slice(&hash, start as u64, &data[start..end]),
+                    "slice at {} of {}",
+                    start,
+                    len
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_corrupted_data_is_rejected() {
+        let mut data = data_of(8193);
+        let (hash, outboard) = BaoOutboard::encode(&data);
+        data[4100] ^= 1;
+
+        assert!(!outboard.verify_slice(&hash, 4096, &data[4096..5120]));
+        assert!(outboard.verify_slice(&hash, 0, &data[..4096]));
+    }
+
+    #[test]
+    fn test_corrupted_outboard_is_rejected() {
+        let data = data_of(8193);
+        let (hash, outboard) = BaoOutboard::encode(&data);
+        // Flip a byte in the root node's right chaining value.
+        let mut bytes = outboard.as_bytes().to_vec();
+        bytes[HEADER_LEN + 40] ^= 1;
+        let tampered = BaoOutboard::from_bytes(&bytes).unwrap();
+
+        assert!(!tampered.verify_slice(&hash, 8192, &data[8192..]));
+
+        // Flip a byte in the last (deepest) node, on the path to chunk 7 only.
+        let mut bytes = outboard.as_bytes().to_vec();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        let tampered = BaoOutboard::from_bytes(&bytes).unwrap();
+
+        assert!(!tampered.verify_slice(&hash, 7168, &data[7168..8192]));
+        assert!(tampered.verify_slice(&hash, 8192, &data[8192..]));
+    }
+
+    #[test]
+    fn test_misaligned_or_out_of_range_slices() {
+        let data = data_of(5000);
+        let (hash, outboard) = BaoOutboard::encode(&data);
+
+        assert!(!outboard.verify_slice(&hash, 1, &data[1..1025]));
+        assert!(!outboard.verify_slice(&hash, 0, &data[..1000]));
+        assert!(!outboard.verify_slice(&hash, 4096, &data_of(1000)));
+        assert!(!outboard.verify_slice(&hash, 0, &[]));
+    }
+
+    #[test]
+    fn test_from_bytes_round_trip() {
+        let (_, outboard) = BaoOutboard::encode(&data_of(3000));
+        assert_eq!(
+            BaoOutboard::from_bytes(outboard.as_bytes()),
+            Some(outboard.clone())
+        );
+
+        let bytes = outboard.as_bytes();
+        assert!(BaoOutboard::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+        assert!(BaoOutboard::from_bytes(&bytes[..4]).is_none());
+    }
+
+    #[test]
+    fn test_for_chunk_matches_chunk_hash() {
+        let data = data_of(3000);
+        let chunk = Chunk::new(data.clone()).set_hash(crate::hash::Blake3Hasher::hash(&data));
+        let outboard = BaoOutboard::for_chunk(&chunk).unwrap();
+        assert!(outboard.verify_slice(&chunk.hash.unwrap(), 2048, &data[2048..]));
+    }
+
+    #[test]
+    fn test_for_chunk_keyed_matches_chunk_hash() {
+        let key = [7u8; 32];
+        let data = data_of(3000);
+        let (hash, _) = BaoOutboard::encode_keyed(&key, &data);
+        let chunk = Chunk::new(data.clone()).set_hash(hash);
+
+        let outboard = BaoOutboard::for_chunk_keyed(&key, &chunk).unwrap();
+        assert!(outboard.verify_slice_keyed(&key, &hash, 2048, &data[2048..]));
+        assert!(BaoOutboard::for_chunk(&chunk).is_err());
+        assert!(BaoOutboard::for_chunk_keyed(&[8u8; 32], &chunk).is_err());
+    }
+
+    #[test]
+    fn test_for_chunk_rejects_unverifiable_hash() {
+        let data = data_of(3000);
+
+        assert!(BaoOutboard::for_chunk(&Chunk::new(data.clone())).is_err());
+
+        let wrong = Chunk::new(data.clone()).set_hash(crate::hash::Blake3Hasher::hash(b"other"));
+        assert!(BaoOutboard::for_chunk(&wrong).is_err());
+
+        let sha = ChunkHash::with_algorithm(HashAlgorithm::Sha256, *blake3::hash(&data).as_bytes());
+        let chunk = Chunk::new(data).set_hash(sha);
+        assert!(BaoOutboard::for_chunk(&chunk).is_err());
+    }
+
+    #[test]
+    fn test_chunker_builds_outboard_with_hash() {
+        let data = data_of(20_000);
+        let key = [7u8; 32];
+        for hash_config in [HashConfig::enabled(), HashConfig::enabled().with_key(key)] {
+            let config = ChunkConfig::new(1024, 4096, 16384)
+                .unwrap()
+                .with_hash_config(hash_config.with_bao_outboard(true));
+            let mut chunker = Chunker::new(config);
+            let mut chunks = chunker.push_buf(bytes::Bytes::from(data.clone()));
+            chunks.extend(chunker.finish());
+
+            assert!(chunks.len() > 1);
+            for chunk in &chunks {
+                let outboard = chunk.outboard().unwrap();
+                let expected = if hash_config.is_keyed() {
+                    BaoOutboard::for_chunk_keyed(&key, chunk)
+                } else {
+                    BaoOutboard::for_chunk(chunk)
+                };
+                assert_eq!(Some(outboard), expected.ok().as_ref());
+            }
+        }
+
+        let mut chunker = Chunker::new(ChunkConfig::new(1024, 4096, 16384).unwrap());
+        let chunks = chunker.push_buf(bytes::Bytes::from(data));
+        assert!(chunks.iter().all(|chunk| chunk.outboard.is_none()));
+    }
+
+    #[test]
+    fn test_outboard_requires_blake3_chunk_hashes() {
+        assert!(
+            HashConfig::enabled()
+                .with_bao_outboard(true)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            HashConfig::disabled()
+                .with_bao_outboard(true)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            HashConfig::enabled()
+                .with_hasher(HashAlgorithm::Blake3, || {
+                    Box::new(crate::hash::Blake3Hasher::new())
+                })
+                .with_bao_outboard(true)
+                .validate()
+                .is_err()
+        );
+    }
+}
@@ -11,13 +11,45 @@
 //! W. Xia et al., "The Design of Fast Content-Defined Chunking for Data
 //! Deduplication Based Storage Systems," IEEE TPDS, vol. 31, no. 9, 2020.
 
+use std::sync::{Arc, LazyLock};
+
 use crate::cdc::tables::{GEAR_TABLE_SHIFTED, MASKS};
+use crate::config::ChunkConfig;
 
 #[cfg(feature = "keyed-cdc")]
 use crate::cdc::tables::generate_keyed_gear_table_shifted;
 
+/// Version of the boundary algorithm, recorded in configuration fingerprints.
+///
+/// Bump whenever the same configuration and input could produce different
+/// boundaries. Version 1 is the 0.9 algorithm: the rolling hash carries
+/// across boundaries, and masks come from [`mask_bits`], which agrees with 0.9
+/// for the power-of-two sizes it accepted.
+pub const ALGORITHM_VERSION: u8 = 1;
+
+/// Returns the number of mask bits for an average chunk size.
+///
+/// This is `log2(avg_size)` rounded to the nearest integer (rounding up from
+/// `2^n * sqrt(2)`), so non-power-of-two sizes use the closest power of two:
+/// 192 KiB maps to 18 bits (256 KiB) and 40 KiB to 15 bits (32 KiB).
+pub fn mask_bits(avg_size: usize) -> usize {
+    let floor = (usize::BITS - 1 - avg_size.max(1).leading_zeros()) as usize;
+    let squared = avg_size as u128 * avg_size as u128;
+    if squared >= 1u128 << (2 * floor + 1) {
+        floor + 1
+    } else {
+        floor
+    }
+}
+
+/// Shared copy of the built-in gear table, so unkeyed instances never copy it.
+static DEFAULT_GEAR_TABLE: LazyLock<Arc<[u64; 256]>> =
+    LazyLock::new(|| Arc::new(GEAR_TABLE_SHIFTED));
+
 /// FastCDC rolling hash state.
-#[derive(Debug)]
+///
+/// Cloning copies the rolling state and shares the gear table.
+#[derive(Debug, Clone)]
 pub struct FastCdc {
     hash: u64,
     min_size: usize,
@@ -26,7 +58,7 @@ pub struct FastCdc {
     bytes_since_boundary: usize,
     mask_s: u64,
     mask_l: u64,
-    gear_table_shifted: [u64; 256],
+    gear_table_shifted: Arc<[u64; 256]>,
 }
 
 impl FastCdc {
@@ -36,6 +68,22 @@ impl FastCdc {
         Self::with_key(min_size, avg_size, max_size, normalization_level, None)
     }
 
+    /// Creates a FastCDC instance for a chunk configuration.
+    pub fn from_config(config: &ChunkConfig) -> Self {
+        #[cfg(feature = "keyed-cdc")]
+        let key = config.keyed_gear_table_key();
+        #[cfg(not(feature = "keyed-cdc"))]
+        let key = None;
+
+        Self::with_key(
+            config.min_size(),
+            config.avg_size(),
+            config.max_size(),
+            config.normalization_level(),
+            key,
+        )
+    }
+
     /// Creates a new FastCDC instance with an optional key for keyed CDC.
     pub fn with_key(
         min_size: usize,
@@ -44,30 +92,22 @@ impl FastCdc {
         normalization_level: u8,
         _key: Option<[u8; 32]>,
     ) -> Self {
-        let avg_bits = avg_size.trailing_zeros() as usize;
+        let top = MASKS.len() - 1;
+        let avg_bits = mask_bits(avg_size).min(top);
         let level = normalization_level as usize;
 
-        let mask_s = if level == 0 {
-            MASKS[avg_bits]
-        } else {
-            MASKS[avg_bits + level]
-        };
-
-        let mask_l = if level == 0 {
-            MASKS[avg_bits]
-        } else {
-            MASKS[avg_bits - level]
-        };
+        let mask_s = MASKS[(avg_bits + level).min(top)];
+        let mask_l = MASKS[avg_bits.saturating_sub(level)];
 
         #[cfg(feature = "keyed-cdc")]
         let gear_table_shifted = if let Some(k) = _key {
-            generate_keyed_gear_table_shifted(k)
+            Arc::new(generate_keyed_gear_table_shifted(k))
         } else {
-            GEAR_TABLE_SHIFTED
+            Arc::clone(&DEFAULT_GEAR_TABLE)
         };
 
         #[cfg(not(feature = "keyed-cdc"))]
-        let gear_table_shifted = GEAR_TABLE_SHIFTED;
+        let gear_table_shifted = Arc::clone(&DEFAULT_GEAR_TABLE);
 
         Self {
             hash: 0,
@@ -81,6 +121,29 @@ impl FastCdc {
         }
     }
 
+    /// Returns the rolling hash and the bytes consumed since the last boundary.
+    pub fn state(&self) -> (u64, usize) {
+        (self.hash, self.bytes_since_boundary)
+    }
+
+    /// Restores state previously returned by [`FastCdc::state`].
+    pub fn set_state(&mut self, hash: u64, bytes_since_boundary: usize) {
+        self.hash = hash;
+        self.bytes_since_boundary = bytes_since_boundary;
+    }
+
+    /// Returns the rolling hash of `data` from a zero state, without looking
+    /// for boundaries.
+    ///
+    /// A byte is shifted out of the hash 64 bytes later, so after `64 + k`
+    /// bytes two different starting states agree unless a carry survived,
+    /// which happens with probability about `2^-k`.
+    pub fn hash_of(&self, data: &[u8]) -> u64 {
+        data.iter().fold(0, |hash: u64, &byte| {
+            (hash >> 1).wrapping_add(self.gear_table_shifted[byte as usize])
+        })
+    }
+
     /// Resets the hash state.
     pub fn reset(&mut self) {
         self.hash = 0;
@@ -88,6 +151,9 @@ impl FastCdc {
     }
 
     /// Updates the hash with a new byte and returns true if a boundary is found.
+    ///
+    /// Only the byte count restarts at a boundary; the rolling hash carries
+    /// into the next chunk.
     pub fn update(&mut self, byte: u8) -> bool {
         self.bytes_since_boundary = self.bytes_since_boundary.saturating_add(1);
 
@@ -102,25 +168,17 @@ impl FastCdc {
             return false;
         }
 
-        if self.bytes_since_boundary >= self.max_size {
+        let mask = if self.bytes_since_boundary >= self.avg_size {
+            self.mask_l
+        } else {
+            self.mask_s
+        };
+
+        if self.bytes_since_boundary >= self.max_size || (self.hash & mask) == 0 {
             self.bytes_since_boundary = 0;
             return true;
         }
 
-        if self.bytes_since_boundary >= self.avg_size {
-            // Large mask check
-            if (self.hash & self.mask_l) == 0 {
-                self.bytes_since_boundary = 0;
-                return true;
-            }
-        } else {
-            // Small mask check
-            if (self.hash & self.mask_s) == 0 {
-                self.bytes_since_boundary = 0;
-                return true;
-            }
-        }
-
         false
     }
 }
@@ -188,7 +246,7 @@ mod tests {
         for (i, byte) in data.iter().enumerate() {
             if cdc.update(*byte) {
                 boundary_count += 1;
-                let chunk_size = (i + 1 - last_boundary_pos) as usize;
+                let chunk_size = i + 1 - last_boundary_pos;
                 assert!(
                     chunk_size <= max_size,
                     "Chunk size {} exceeds max_size {}",
@@ -198,6 +256,80 @@ mod tests {
                 last_boundary_pos = i + 1;
             }
         }
+
+        assert!(boundary_count > 0, "max_size must force a boundary");
+    }
+
+    #[test]
+    fn test_fastcdc_boundary_keeps_hash() {
+        let data: Vec<u8> = (0..2000u32).map(|i| (i * 73 % 256) as u8).collect();
+        let mut cdc = FastCdc::new(4, 16, 64, 2);
+        let first = data.iter().position(|&b| cdc.update(b)).unwrap() + 1;
+        assert_eq!(cdc.state(), (cdc.hash_of(&data[..first]), 0));
+
+        // The carried hash washes out: a fresh instance on the tail agrees
+        // once both have seen enough bytes.
+        let mut fresh = FastCdc::new(4, 16, 64, 2);
+        for &byte in &data[first..first + 128] {
+            cdc.update(byte);
+            fresh.update(byte);
+        }
+        assert_eq!(cdc.state().0, fresh.state().0);
+    }
+
+    #[test]
+    fn test_fastcdc_hash_of_matches_update() {
+        let data: Vec<u8> = (0..300u32).map(|i| (i * 31 % 256) as u8).collect();
+        let mut cdc = FastCdc::new(4096, 16384, 65536, 2);
+        for &byte in &data {
+            assert!(!cdc.update(byte));
+        }
+        assert_eq!(cdc.state(), (cdc.hash_of(&data), 300));
+
+        let mut restored = FastCdc::new(4096, 16384, 65536, 2);
+        restored.set_state(cdc.state().0, 300);
+        assert_eq!(restored.state(), cdc.state());
+    }
+
+    #[test]
+    fn test_fastcdc_clone_continues_identically() {
+        let data: Vec<u8> = (0..2000u32).map(|i| (i * 97 % 256) as u8).collect();
+        let mut cdc = FastCdc::new(4, 16, 64, 2);
+        for &byte in &data[..37] {
+            cdc.update(byte);
+        }
+
+        let mut fork = cdc.clone();
+        assert!(Arc::ptr_eq(
+            &cdc.gear_table_shifted,
+            &fork.gear_table_shifted
+        ));
+        for &byte in &data[37..] {
+            assert_eq!(cdc.update(byte), fork.update(byte));
+        }
+    }
+
+    #[test]
+    fn test_mask_bits_rounds_to_nearest_log2() {
+        assert_eq!(mask_bits(1), 0);
+        assert_eq!(mask_bits(16 * 1024), 14);
+        assert_eq!(mask_bits(192 * 1024), 18);
+        assert_eq!(mask_bits(40 * 1024), 15);
+        // 2^14 * sqrt(2) ~= 23170.5
+        assert_eq!(mask_bits(23170), 14);
+        assert_eq!(mask_bits(23171), 15);
+    }
+
+    #[test]
+    fn test_fastcdc_non_power_of_two_sizes() {
+        let cdc = FastCdc::new(48 * 1024, 192 * 1024, 768 * 1024, 2);
+        assert_eq!(cdc.mask_s, MASKS[20]);
+        assert_eq!(cdc.mask_l, MASKS[16]);
+
+        // Normalization never indexes past the mask table.
+        let cdc = FastCdc::new(1, 3, usize::MAX, 31);
+        assert_eq!(cdc.mask_s, MASKS[MASKS.len() - 1]);
+        assert_eq!(cdc.mask_l, MASKS[0]);
     }
 
     #[test]
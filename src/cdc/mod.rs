@@ -6,4 +6,5 @@ mod fastcdc;
 mod tables;
 
 // Re-export for use within the crate (cdc module is private, so pub is crate-local)
-pub use fastcdc::FastCdc;
+pub use fastcdc::{ALGORITHM_VERSION, FastCdc, mask_bits};
+pub use tables::{GEAR_TABLE_VERSION, MASK_TABLE_VERSION};
@@ -1,5 +1,15 @@
 //! FastCDC lookup tables.
 
+/// Version of [`GEAR_TABLE`], recorded in configuration fingerprints.
+///
+/// Bump whenever a table value changes.
+pub const GEAR_TABLE_VERSION: u8 = 1;
+
+/// Version of [`MASKS`], recorded in configuration fingerprints.
+///
+/// Bump whenever a mask changes.
+pub const MASK_TABLE_VERSION: u8 = 1;
+
 /// Base gear hash table for FastCDC.
 pub const GEAR_TABLE: [u64; 256] = [
     0x4d65822107fcfd52,
@@ -345,3 +355,31 @@ pub fn generate_keyed_gear_table_shifted(key: [u8; 32]) -> [u64; 256] {
     }
     shifted
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(table: &[u64]) -> u64 {
+        table
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+
+    #[test]
+    fn test_tables_match_their_versions() {
+        assert_eq!(
+            (GEAR_TABLE_VERSION, digest(&GEAR_TABLE)),
+            (1, 0x531a_6511_bca1_a58f),
+            "GEAR_TABLE changed: bump GEAR_TABLE_VERSION and update this digest"
+        );
+        assert_eq!(
+            (MASK_TABLE_VERSION, digest(&MASKS)),
+            (1, 0x14ca_0af1_8e3b_903c),
+            "MASKS changed: bump MASK_TABLE_VERSION and update this digest"
+        );
+    }
+}
@@ -11,6 +11,9 @@ use super::ChunkHash;
 /// - Data ([`Bytes`]) - zero-copy reference
 /// - Offset ([`Option<u64>`]) - position in stream
 /// - Hash ([`Option<ChunkHash>`]) - BLAKE3 hash if enabled
+/// - Weak hash ([`Option<u32>`]) - Adler-32 checksum if enabled
+/// - Outboard ([`Option<BaoOutboard>`](crate::BaoOutboard)) - Bao tree if
+///   enabled (requires `bao` feature)
 ///
 /// # Example
 ///
@@ -41,6 +44,15 @@ pub struct Chunk {
 
     /// The content hash of this chunk.
     pub hash: Option<ChunkHash>,
+
+    /// The Adler-32 weak checksum of this chunk.
+    pub weak_hash: Option<u32>,
+
+    /// The Bao outboard tree of this chunk, built with its hash when
+    /// [`HashConfig::with_bao_outboard`](crate::HashConfig::with_bao_outboard)
+    /// is set.
+    #[cfg(feature = "bao")]
+    pub outboard: Option<crate::BaoOutboard>,
 }
 
 impl Chunk {
@@ -50,6 +62,9 @@ impl Chunk {
             data: data.into(),
             offset: None,
             hash: None,
+            weak_hash: None,
+            #[cfg(feature = "bao")]
+            outboard: None,
         }
     }
 
@@ -59,6 +74,9 @@ impl Chunk {
             data: data.into(),
             offset: Some(offset),
             hash: None,
+            weak_hash: None,
+            #[cfg(feature = "bao")]
+            outboard: None,
         }
     }
 
@@ -68,9 +86,20 @@ impl Chunk {
             data: data.into(),
             offset: None,
             hash: Some(hash),
+            weak_hash: None,
+            #[cfg(feature = "bao")]
+            outboard: None,
         }
     }
 
+    /// Returns the chunk's IPFS CIDv1 (`raw` codec), if it has a hash.
+    ///
+    /// With SHA-256 chunk hashes this is the CID IPFS assigns the block.
+    #[cfg(feature = "ipfs")]
+    pub fn cid(&self) -> Option<crate::ipfs::Cid> {
+        self.hash.map(crate::ipfs::Cid::raw)
+    }
+
     /// Sets the offset for this chunk.
     pub fn set_offset(mut self, offset: u64) -> Self {
         self.offset = Some(offset);
@@ -83,6 +112,12 @@ impl Chunk {
         self
     }
 
+    /// Sets the weak checksum for this chunk.
+    pub fn set_weak_hash(mut self, weak_hash: u32) -> Self {
+        self.weak_hash = Some(weak_hash);
+        self
+    }
+
     /// Returns the length of the chunk data.
     pub fn len(&self) -> usize {
         self.data.len()
@@ -108,6 +143,17 @@ impl Chunk {
         self.hash
     }
 
+    /// Returns the Adler-32 weak checksum of the chunk data, if computed.
+    pub fn weak_hash(&self) -> Option<u32> {
+        self.weak_hash
+    }
+
+    /// Returns the Bao outboard tree of the chunk data, if built.
+    #[cfg(feature = "bao")]
+    pub fn outboard(&self) -> Option<&crate::BaoOutboard> {
+        self.outboard.as_ref()
+    }
+
     /// Returns the start offset of the chunk.
     pub fn start(&self) -> u64 {
         self.offset.unwrap_or(0)
@@ -1,16 +1,27 @@
 //! Cryptographic hash for chunk identity.
 
 use std::fmt;
-use std::hash::{Hash as StdHash, Hasher};
+
+use crate::hash::HashAlgorithm;
 
 /// A fixed-size cryptographic hash representing chunk content.
 ///
-/// 32-byte BLAKE3 hash wrapper with:
+/// 32-byte hash wrapper with:
 /// - Type safety
+/// - Algorithm tag ([`HashAlgorithm`]) recording what produced the digest
 /// - Hex encoding/decoding
 /// - Display formatting
 /// - Standard trait implementations
 ///
+/// Hashes produced by different algorithms never compare equal. Digests
+/// shorter than 32 bytes are zero-padded; [`ChunkHash::digest`] returns only
+/// the significant bytes. Convert to [`ChunkId`](crate::ChunkId) for a
+/// self-describing encoding.
+///
+/// With the `serde` feature, hashes serialize in that self-describing form so
+/// the algorithm survives a round trip: multihash hex (e.g. `"1e20…"` for
+/// BLAKE3) in human-readable formats, multihash bytes otherwise.
+///
 /// # Example
 ///
 /// ```
@@ -20,79 +31,137 @@ use std::hash::{Hash as StdHash, Hasher};
 /// let hex = hash.to_hex();
 /// assert_eq!(hex.len(), 64);
 ///
-/// let parsed = ChunkHash::from_hex(&hex).unwrap();
+/// let parsed = ChunkHash::from_hex_with_algorithm(hash.algorithm(), &hex).unwrap();
 /// assert_eq!(hash, parsed);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ChunkHash([u8; 32]);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChunkHash {
+    algorithm: HashAlgorithm,
+    bytes: [u8; 32],
+}
 
 impl ChunkHash {
     /// The size of the hash in bytes (256 bits).
     pub const SIZE: usize = 32;
 
-    /// Creates a new chunk hash from a byte array.
+    /// Creates a new BLAKE3 chunk hash from a byte array.
     pub const fn new(bytes: [u8; 32]) -> Self {
-        Self(bytes)
+        Self::with_algorithm(HashAlgorithm::Blake3, bytes)
     }
 
-    /// Creates a new chunk hash from a slice.
+    /// Creates a new chunk hash produced by the given algorithm.
+    pub const fn with_algorithm(algorithm: HashAlgorithm, bytes: [u8; 32]) -> Self {
+        Self { algorithm, bytes }
+    }
+
+    /// Creates a new BLAKE3 chunk hash from a slice.
+    ///
+    /// The result is always tagged [`HashAlgorithm::Blake3`]; use
+    /// [`ChunkHash::from_digest`] for digests of other algorithms.
     ///
     /// Returns `None` if the slice is not exactly 32 bytes.
+    pub fn from_blake3_slice(slice: &[u8]) -> Option<Self> {
+        Self::from_digest(HashAlgorithm::Blake3, slice)
+    }
+
+    /// Creates a new BLAKE3 chunk hash from a slice.
+    #[deprecated(note = "always tags the hash as BLAKE3; use `from_digest` or `from_blake3_slice`")]
     pub fn from_slice(slice: &[u8]) -> Option<Self> {
-        if slice.len() != 32 {
+        Self::from_blake3_slice(slice)
+    }
+
+    /// Creates a chunk hash from a digest produced by `algorithm`.
+    ///
+    /// Returns `None` if the slice length differs from
+    /// [`HashAlgorithm::digest_len`].
+    pub fn from_digest(algorithm: HashAlgorithm, digest: &[u8]) -> Option<Self> {
+        if digest.len() != algorithm.digest_len() {
             return None;
         }
         let mut bytes = [0u8; 32];
-        bytes.copy_from_slice(slice);
-        Some(Self(bytes))
+        bytes[..digest.len()].copy_from_slice(digest);
+        Some(Self { algorithm, bytes })
+    }
+
+    /// Returns the algorithm that produced this hash.
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns `true` if the hash was produced by a cryptographic algorithm.
+    ///
+    /// Non-cryptographic hashes (such as XXH3) must not be used as content
+    /// identities for untrusted data.
+    pub fn is_cryptographic(&self) -> bool {
+        self.algorithm.is_cryptographic()
     }
 
     /// Returns the hash as a byte array reference.
     pub fn as_bytes(&self) -> &[u8; 32] {
-        &self.0
+        &self.bytes
     }
 
-    /// Converts the hash to a hexadecimal string.
+    /// Returns the significant digest bytes.
+    pub fn digest(&self) -> &[u8] {
+        &self.bytes[..self.algorithm.digest_len()]
+    }
+
+    /// Converts the digest to a hexadecimal string.
+    ///
+    /// The string does not record the algorithm; parse it back with
+    /// [`ChunkHash::from_hex_with_algorithm`], or use the self-describing
+    /// [`ChunkId::to_hex`](crate::ChunkId::to_hex) form, which
+    /// [`ChunkHash::from_hex`] reads for any algorithm.
     pub fn to_hex(&self) -> String {
-        let mut hex = String::with_capacity(64);
-        for byte in &self.0 {
-            hex.push_str(&format!("{:02x}", byte));
-        }
-        hex
+        crate::util::encode_hex(self.digest())
     }
 
-    /// Parses a hash from a hexadecimal string.
+    /// Parses a BLAKE3 hash from a hexadecimal string.
+    ///
+    /// The result is always tagged [`HashAlgorithm::Blake3`]; use
+    /// [`ChunkHash::from_hex_with_algorithm`] for hashes of other algorithms.
     ///
     /// Returns `None` if the string is not exactly 64 hex characters.
+    pub fn from_blake3_hex(hex: &str) -> Option<Self> {
+        Self::from_hex_with_algorithm(HashAlgorithm::Blake3, hex)
+    }
+
+    /// Parses a hash from a hexadecimal string.
+    ///
+    /// Accepts either a plain 64-character digest, read as BLAKE3 like
+    /// [`ChunkHash::from_blake3_hex`], or the multihash hex form of
+    /// [`ChunkId::to_hex`](crate::ChunkId::to_hex), which records the
+    /// algorithm and so round-trips hashes of every algorithm.
+    ///
+    /// Returns `None` if the string is neither, or if the multihash digest
+    /// length does not match its algorithm.
     pub fn from_hex(hex: &str) -> Option<Self> {
-        if hex.len() != 64 {
-            return None;
+        if hex.len() == Self::SIZE * 2 {
+            return Self::from_blake3_hex(hex);
         }
+        Self::try_from(crate::ChunkId::from_hex(hex)?).ok()
+    }
 
-        let mut bytes = [0u8; 32];
-        for (i, chunk) in hex.as_bytes().chunks(2).enumerate() {
-            let byte_str = std::str::from_utf8(chunk).ok()?;
-            bytes[i] = u8::from_str_radix(byte_str, 16).ok()?;
+    /// Parses a hash produced by `algorithm` from a hexadecimal string.
+    ///
+    /// Returns `None` if the string is not exactly twice
+    /// [`HashAlgorithm::digest_len`] hex characters.
+    pub fn from_hex_with_algorithm(algorithm: HashAlgorithm, hex: &str) -> Option<Self> {
+        if hex.len() != algorithm.digest_len() * 2 {
+            return None;
         }
-
-        Some(Self(bytes))
+        Self::from_digest(algorithm, &crate::util::decode_hex(hex)?)
     }
 
     /// Checks if this hash is all zeros.
     pub fn is_zero(&self) -> bool {
-        self.0.iter().all(|&b| b == 0)
+        self.bytes.iter().all(|&b| b == 0)
     }
 }
 
 impl AsRef<[u8]> for ChunkHash {
     fn as_ref(&self) -> &[u8] {
-        &self.0
-    }
-}
-
-impl StdHash for ChunkHash {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.hash(state);
+        self.digest()
     }
 }
 
@@ -102,7 +171,25 @@ impl fmt::Display for ChunkHash {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChunkHash {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::ChunkId::from(*self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChunkHash {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = crate::ChunkId::deserialize(deserializer)?;
+        ChunkHash::try_from(id).map_err(|_| {
+            serde::de::Error::custom("digest length does not match the hash algorithm")
+        })
+    }
+}
+
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use super::*;
 
@@ -147,6 +234,21 @@ mod tests {
         assert!(ChunkHash::from_hex("").is_none());
         assert!(ChunkHash::from_hex("ab").is_none());
         assert!(ChunkHash::from_hex(&"ab".repeat(33)).is_none());
+        assert!(ChunkHash::from_hex(&format!("1220{}", "ab".repeat(31))).is_none());
+    }
+
+    #[test]
+    fn test_chunk_hash_from_hex_multihash_round_trip() {
+        let sha256 = ChunkHash::with_algorithm(HashAlgorithm::Sha256, [0x5A; 32]);
+        let hex = crate::ChunkId::from(sha256).to_hex();
+        assert!(hex.starts_with("1220"));
+        assert_eq!(ChunkHash::from_hex(&hex), Some(sha256));
+
+        // The plain digest alone cannot say which algorithm produced it.
+        assert_eq!(
+            ChunkHash::from_hex(&sha256.to_hex()).map(|h| h.algorithm()),
+            Some(HashAlgorithm::Blake3)
+        );
     }
 
     #[test]
@@ -181,6 +283,51 @@ mod tests {
         assert!(hash1 < hash2);
     }
 
+    #[test]
+    fn test_chunk_hash_algorithm_tag() {
+        let blake3 = ChunkHash::new([0x42; 32]);
+        let custom = ChunkHash::with_algorithm(HashAlgorithm::Custom(7), [0x42; 32]);
+
+        assert_eq!(blake3.algorithm(), HashAlgorithm::Blake3);
+        assert_eq!(custom.algorithm(), HashAlgorithm::Custom(7));
+        assert_ne!(
+            blake3, custom,
+            "Different algorithms must never compare equal"
+        );
+    }
+
+    #[test]
+    fn test_chunk_hash_from_hex_with_algorithm() {
+        let hash = ChunkHash::with_algorithm(HashAlgorithm::Custom(7), [0x5A; 32]);
+        let parsed =
+            ChunkHash::from_hex_with_algorithm(HashAlgorithm::Custom(7), &hash.to_hex()).unwrap();
+        assert_eq!(hash, parsed);
+    }
+
+    #[test]
+    fn test_chunk_hash_blake3_constructors() {
+        let hash = ChunkHash::new([0xCD; 32]);
+        assert_eq!(ChunkHash::from_blake3_hex(&hash.to_hex()), Some(hash));
+        assert_eq!(ChunkHash::from_blake3_slice(hash.digest()), Some(hash));
+        assert!(ChunkHash::from_blake3_hex(&"cd".repeat(16)).is_none());
+    }
+
+    #[test]
+    fn test_chunk_hash_hex_round_trip_keeps_algorithm() {
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(&[0x5A; 16]);
+        let hash = ChunkHash::with_algorithm(HashAlgorithm::Xxh3, bytes);
+        let hex = hash.to_hex();
+        assert_eq!(hex.len(), 32);
+        assert_eq!(
+            ChunkHash::from_hex_with_algorithm(hash.algorithm(), &hex),
+            Some(hash)
+        );
+        assert!(
+            ChunkHash::from_hex_with_algorithm(HashAlgorithm::Xxh3, &"zz".repeat(16)).is_none()
+        );
+    }
+
     #[test]
     fn test_chunk_hash_as_ref() {
         let bytes = [0xAB; 32];
@@ -188,4 +335,26 @@ mod tests {
         let slice: &[u8] = hash.as_ref();
         assert_eq!(slice, &bytes[..]);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_chunk_hash_serde() {
+        let hash = ChunkHash::with_algorithm(HashAlgorithm::Sha256, [0xAB; 32]);
+
+        let json = serde_json::to_string(&hash).unwrap();
+        assert_eq!(json, format!("\"1220{}\"", "ab".repeat(32)));
+        assert_eq!(serde_json::from_str::<ChunkHash>(&json).unwrap(), hash);
+
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&hash, &mut cbor).unwrap();
+        assert_eq!(cbor.len(), 2 + 34);
+        assert_eq!(
+            ciborium::from_reader::<ChunkHash, _>(&cbor[..]).unwrap(),
+            hash
+        );
+
+        // A 20-byte SHA-256 digest is a valid multihash but not a ChunkHash.
+        let short = format!("\"1214{}\"", "ab".repeat(20));
+        assert!(serde_json::from_str::<ChunkHash>(&short).is_err());
+    }
 }
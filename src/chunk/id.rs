@@ -0,0 +1,347 @@
+//! Self-describing chunk identifier in multihash format.
+
+use std::fmt;
+
+use super::ChunkHash;
+use crate::hash::HashAlgorithm;
+use crate::util;
+
+/// Maximum digest length a [`ChunkId`] can hold (512 bits).
+const MAX_DIGEST_LEN: usize = 64;
+
+/// A self-describing chunk identifier.
+///
+/// Encodes the hash algorithm, digest length and digest in
+/// [multihash](https://multiformats.io/multihash/) layout:
+///
+/// ```text
+/// <varint algorithm code><varint digest length><digest>
+/// ```
+///
+/// Unlike [`ChunkHash`], a `ChunkId` carries its algorithm in every encoded
+/// form, so stores mixing several algorithms can key on it directly.
+///
+/// Forms:
+/// - Binary: [`ChunkId::to_bytes`] / [`ChunkId::from_bytes`]
+/// - Hex: [`ChunkId::to_hex`] / [`ChunkId::from_hex`]
+/// - Multibase: [`ChunkId::to_base32`] (`b…`), [`ChunkId::to_base58`] (`z…`)
+///   and [`ChunkId::from_multibase`]
+/// - Serde (requires `serde` feature): the hex form in human-readable
+///   formats such as JSON, the binary form otherwise
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::{ChunkHash, ChunkId, HashAlgorithm};
+///
+/// let hash = ChunkHash::with_algorithm(HashAlgorithm::Sha256, [0xAB; 32]);
+/// let id = ChunkId::from(hash);
+///
+/// assert!(id.to_hex().starts_with("1220"));
+/// assert_eq!(ChunkId::from_multibase(&id.to_base58()), Some(id));
+/// assert_eq!(ChunkHash::try_from(id), Ok(hash));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChunkId {
+    code: u64,
+    len: u8,
+    digest: [u8; MAX_DIGEST_LEN],
+}
+
+impl ChunkId {
+    /// Creates an identifier from a multicodec code and digest.
+    ///
+    /// Returns `None` if the digest is longer than 64 bytes.
+    pub fn new(code: u64, digest: &[u8]) -> Option<Self> {
+        if digest.len() > MAX_DIGEST_LEN {
+            return None;
+        }
+        let mut buf = [0u8; MAX_DIGEST_LEN];
+        buf[..digest.len()].copy_from_slice(digest);
+        Some(Self {
+            code,
+            len: digest.len() as u8,
+            digest: buf,
+        })
+    }
+
+    /// Returns the multicodec code of the hash algorithm.
+    pub fn code(&self) -> u64 {
+        self.code
+    }
+
+    /// Returns the hash algorithm identified by the code.
+    pub fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::from_multihash_code(self.code)
+    }
+
+    /// Returns the digest bytes.
+    pub fn digest(&self) -> &[u8] {
+        &self.digest[..self.len as usize]
+    }
+
+    /// Encodes the identifier in binary multihash form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(4 + self.len as usize);
+        util::write_varint(self.code, &mut out);
+        util::write_varint(u64::from(self.len), &mut out);
+        out.extend_from_slice(self.digest());
+        out
+    }
+
+    /// Decodes a binary multihash.
+    ///
+    /// Returns `None` on malformed varints, a length mismatch or trailing bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (code, code_len) = util::read_varint(bytes)?;
+        let (len, len_len) = util::read_varint(&bytes[code_len..])?;
+        let digest = &bytes[code_len + len_len..];
+        if digest.len() as u64 != len {
+            return None;
+        }
+        Self::new(code, digest)
+    }
+
+    /// Encodes the binary form as lowercase hexadecimal.
+    pub fn to_hex(&self) -> String {
+        util::encode_hex(&self.to_bytes())
+    }
+
+    /// Decodes the hexadecimal form.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        Self::from_bytes(&util::decode_hex(hex)?)
+    }
+
+    /// Encodes as multibase base32 (lowercase, unpadded, `b` prefix).
+    pub fn to_base32(&self) -> String {
+        format!("b{}", util::encode_base32(&self.to_bytes()))
+    }
+
+    /// Encodes as multibase base58btc (`z` prefix).
+    pub fn to_base58(&self) -> String {
+        format!("z{}", util::encode_base58(&self.to_bytes()))
+    }
+
+    /// Decodes a multibase string.
+    ///
+    /// Supports base16 (`f`/`F`), base32 (`b`/`B`) and base58btc (`z`).
+    pub fn from_multibase(text: &str) -> Option<Self> {
+        let mut chars = text.chars();
+        let prefix = chars.next()?;
+        let body = chars.as_str();
+        let bytes = match prefix {
+            'f' | 'F' => util::decode_hex(body)?,
+            'b' | 'B' => util::decode_base32(body)?,
+            'z' => util::decode_base58(body)?,
+            _ => return None,
+        };
+        Self::from_bytes(&bytes)
+    }
+}
+
+impl From<ChunkHash> for ChunkId {
+    fn from(hash: ChunkHash) -> Self {
+        let digest = hash.digest();
+        let mut buf = [0u8; MAX_DIGEST_LEN];
+        buf[..digest.len()].copy_from_slice(digest);
+        Self {
+            code: hash.algorithm().multihash_code(),
+            len: digest.len() as u8,
+            digest: buf,
+        }
+    }
+}
+
+impl TryFrom<ChunkId> for ChunkHash {
+    type Error = ChunkId;
+
+    /// Converts back to a [`ChunkHash`].
+    ///
+    /// Fails, returning the identifier unchanged, if the digest length does not
+    /// match the algorithm's [`HashAlgorithm::digest_len`].
+    fn try_from(id: ChunkId) -> Result<Self, Self::Error> {
+        ChunkHash::from_digest(id.algorithm(), id.digest()).ok_or(id)
+    }
+}
+
+impl AsRef<[u8]> for ChunkId {
+    fn as_ref(&self) -> &[u8] {
+        self.digest()
+    }
+}
+
+impl fmt::Debug for ChunkId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkId")
+            .field("algorithm", &self.algorithm())
+            .field("digest", &util::encode_hex(self.digest()))
+            .finish()
+    }
+}
+
+impl fmt::Display for ChunkId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_base32())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChunkId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_hex())
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChunkId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, SeqAccess, Unexpected, Visitor};
+
+        struct IdVisitor;
+
+        impl<'de> Visitor<'de> for IdVisitor {
+            type Value = ChunkId;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a multihash as a hex string or bytes")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<ChunkId, E> {
+                ChunkId::from_hex(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+            }
+
+            fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<ChunkId, E> {
+                ChunkId::from_bytes(v).ok_or_else(|| E::invalid_value(Unexpected::Bytes(v), &self))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<ChunkId, A::Error> {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(80));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                self.visit_bytes(&bytes)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(IdVisitor)
+        } else {
+            deserializer.deserialize_bytes(IdVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    // BLAKE3 of the empty input.
+    const BLAKE3_EMPTY: &str = "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262";
+
+    fn blake3_empty() -> ChunkHash {
+        ChunkHash::from_blake3_hex(BLAKE3_EMPTY).unwrap()
+    }
+
+    #[test]
+    fn test_chunk_id_binary_layout() {
+        let id = ChunkId::from(blake3_empty());
+        let bytes = id.to_bytes();
+
+        assert_eq!(&bytes[..2], &[0x1e, 0x20]);
+        assert_eq!(&bytes[2..], blake3_empty().as_bytes());
+        assert_eq!(id.to_hex(), format!("1e20{}", BLAKE3_EMPTY));
+        assert_eq!(ChunkId::from_bytes(&bytes), Some(id));
+    }
+
+    #[test]
+    fn test_chunk_id_multi_byte_code() {
+        let hash = ChunkHash::from_digest(HashAlgorithm::Xxh3, &[0x11; 16]).unwrap();
+        let id = ChunkId::from(hash);
+
+        assert_eq!(&id.to_bytes()[..4], &[0xe4, 0xe7, 0x02, 0x10]);
+        assert_eq!(id.algorithm(), HashAlgorithm::Xxh3);
+        assert_eq!(ChunkHash::try_from(id), Ok(hash));
+    }
+
+    #[test]
+    fn test_chunk_id_multibase_round_trip() {
+        let id = ChunkId::from(blake3_empty());
+
+        let base32 = id.to_base32();
+        assert!(base32.starts_with("bdyq"));
+        assert_eq!(ChunkId::from_multibase(&base32), Some(id));
+        assert_eq!(ChunkId::from_multibase(&base32.to_uppercase()), Some(id));
+
+        let base58 = id.to_base58();
+        assert!(base58.starts_with('z'));
+        assert_eq!(ChunkId::from_multibase(&base58), Some(id));
+
+        assert_eq!(
+            ChunkId::from_multibase(&format!("f{}", id.to_hex())),
+            Some(id)
+        );
+        assert_eq!(id.to_string(), base32);
+    }
+
+    #[test]
+    fn test_chunk_id_rejects_malformed() {
+        let bytes = ChunkId::from(blake3_empty()).to_bytes();
+
+        assert!(ChunkId::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+        assert!(ChunkId::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_none());
+        assert!(ChunkId::from_bytes(&[]).is_none());
+        assert!(ChunkId::from_multibase("").is_none());
+        assert!(ChunkId::from_multibase("qabc").is_none());
+        assert!(ChunkId::new(0x12, &[0; 65]).is_none());
+    }
+
+    #[test]
+    fn test_chunk_id_to_chunk_hash_length_mismatch() {
+        let id = ChunkId::new(0x12, &[0xAA; 20]).unwrap();
+        assert_eq!(ChunkHash::try_from(id), Err(id));
+    }
+
+    #[test]
+    fn test_chunk_id_distinguishes_algorithms() {
+        let blake3 = ChunkId::from(ChunkHash::new([7; 32]));
+        let sha256 = ChunkId::from(ChunkHash::with_algorithm(HashAlgorithm::Sha256, [7; 32]));
+        assert_ne!(blake3, sha256);
+        assert_ne!(blake3.to_bytes(), sha256.to_bytes());
+    }
+
+    #[test]
+    fn test_chunk_id_as_map_key() {
+        let a = ChunkId::from(ChunkHash::new([1; 32]));
+        let b = ChunkId::from(ChunkHash::new([2; 32]));
+
+        let mut hash_map = HashMap::new();
+        hash_map.insert(a, "a");
+        hash_map.insert(b, "b");
+        assert_eq!(hash_map[&a], "a");
+
+        let tree: BTreeMap<_, _> = [(b, 2), (a, 1)].into_iter().collect();
+        assert_eq!(tree.keys().copied().collect::<Vec<_>>(), vec![a, b]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_chunk_id_serde() {
+        let id = ChunkId::from(blake3_empty());
+
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"1e20{}\"", BLAKE3_EMPTY));
+        assert_eq!(serde_json::from_str::<ChunkId>(&json).unwrap(), id);
+        assert!(serde_json::from_str::<ChunkId>("\"1e20ab\"").is_err());
+
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&id, &mut cbor).unwrap();
+        assert_eq!(&cbor[..2], &[0x58, 34]); // byte string of 34 bytes
+        assert_eq!(&cbor[2..], id.to_bytes().as_slice());
+        assert_eq!(ciborium::from_reader::<ChunkId, _>(&cbor[..]).unwrap(), id);
+    }
+}
@@ -0,0 +1,91 @@
+//! Data-free chunk metadata.
+
+use super::{Chunk, ChunkHash};
+
+/// The position and identity of a chunk, without its data.
+///
+/// Produced by [`MetaChunker`](crate::MetaChunker) for indexing
+/// jobs that only need `(offset, len, hash)`. Unlike [`Chunk`], a `ChunkMeta`
+/// holds no [`Bytes`](bytes::Bytes) slice, so it never keeps the input buffer
+/// alive. With the `serde` feature it can be shipped as JSON, CBOR and the
+/// like.
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::{Chunk, ChunkMeta};
+///
+/// let chunk = Chunk::with_offset(&b"hello"[..], 10);
+/// let meta = ChunkMeta::from(&chunk);
+/// assert_eq!(meta.range(), 10..15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChunkMeta {
+    /// The offset in the original stream.
+    pub offset: u64,
+
+    /// The chunk length in bytes.
+    pub len: u64,
+
+    /// The content hash of this chunk.
+    pub hash: Option<ChunkHash>,
+
+    /// The Adler-32 weak checksum of this chunk.
+    pub weak_hash: Option<u32>,
+}
+
+impl ChunkMeta {
+    /// Returns the end offset of the chunk (exclusive).
+    pub fn end(&self) -> u64 {
+        self.offset + self.len
+    }
+
+    /// Returns the chunk as a range `[offset, end)`.
+    pub fn range(&self) -> std::ops::Range<u64> {
+        self.offset..self.end()
+    }
+}
+
+impl From<&Chunk> for ChunkMeta {
+    fn from(chunk: &Chunk) -> Self {
+        Self {
+            offset: chunk.start(),
+            len: chunk.len() as u64,
+            hash: chunk.hash,
+            weak_hash: chunk.weak_hash,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_meta_serde() {
+        let meta = ChunkMeta {
+            offset: 4096,
+            len: 100,
+            hash: Some(ChunkHash::new([0x11; 32])),
+            weak_hash: None,
+        };
+
+        let json = serde_json::to_string(&meta).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"offset":4096,"len":100,"hash":"1e20{}","weak_hash":null}}"#,
+                "11".repeat(32)
+            )
+        );
+        assert_eq!(serde_json::from_str::<ChunkMeta>(&json).unwrap(), meta);
+
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&meta, &mut cbor).unwrap();
+        assert_eq!(
+            ciborium::from_reader::<ChunkMeta, _>(&cbor[..]).unwrap(),
+            meta
+        );
+    }
+}
@@ -2,9 +2,15 @@
 //!
 //! - [`Chunk`] - Content-defined chunk with data, offset, hash
 //! - [`ChunkHash`] - 32-byte cryptographic hash
+//! - [`ChunkId`] - Self-describing multihash identifier
+//! - [`ChunkMeta`] - Chunk position and hashes without data
 
 mod data;
 mod hash;
+mod id;
+mod meta;
 
 pub use data::Chunk;
 pub use hash::ChunkHash;
+pub use id::ChunkId;
+pub use meta::ChunkMeta;
@@ -0,0 +1,217 @@
+//! Resumable chunker state.
+
+use crate::cdc::FastCdc;
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+
+/// Encoded size of a version 1 checkpoint.
+const ENCODED_LEN: usize = 1 + 6 * 8;
+
+/// Pending length from which restored bytes are checked against the rolling
+/// hash; a carry from earlier bytes survives this far with probability about
+/// `2^-64`.
+const REPLAY_CHECK_LEN: usize = 128;
+
+/// A snapshot of a [`Chunker`](crate::Chunker)'s position in a stream.
+///
+/// Taken with [`Chunker::checkpoint`](crate::Chunker::checkpoint) and
+/// restored with [`Chunker::restore`](crate::Chunker::restore). Together with
+/// the last [`pending_len`](Self::pending_len) bytes before
+/// [`offset`](Self::offset) (re-read from the source), it lets a long-running
+/// job continue after a restart and produce exactly the boundaries an
+/// uninterrupted run would.
+///
+/// The checkpoint holds no data. Its binary form is versioned
+/// ([`Checkpoint::VERSION`]): a version byte followed by the six fields as
+/// little-endian `u64`s, in declaration order.
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::{Checkpoint, ChunkConfig, Chunker};
+/// use bytes::Bytes;
+///
+/// let config = ChunkConfig::new(16, 64, 256)?;
+/// let data: Vec<u8> = (0..2000u32).map(|i| (i * 7 % 256) as u8).collect();
+///
+/// let mut chunker = Chunker::new(config);
+/// let mut chunks = chunker.push_buf(Bytes::copy_from_slice(&data[..1000]));
+/// let saved = chunker.checkpoint().to_bytes();
+/// drop(chunker); // restart
+///
+/// let checkpoint = Checkpoint::from_bytes(&saved).unwrap();
+/// let resume = (checkpoint.offset + checkpoint.pending_len) as usize;
+/// let pending = Bytes::copy_from_slice(&data[checkpoint.offset as usize..resume]);
+/// let mut chunker = Chunker::restore(config, &checkpoint, pending)?;
+///
+/// chunks.extend(chunker.push_buf(Bytes::copy_from_slice(&data[resume..])));
+/// chunks.extend(chunker.finish());
+/// assert_eq!(chunks.iter().map(|c| c.len()).sum::<usize>(), 2000);
+/// # Ok::<(), chunkrs::ChunkError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Checkpoint {
+    /// Rolling hash state.
+    pub rolling_hash: u64,
+
+    /// Bytes consumed since the last boundary.
+    pub bytes_since_boundary: u64,
+
+    /// Stream offset of the first pending byte (the end of the last chunk).
+    pub offset: u64,
+
+    /// Number of chunks emitted so far.
+    pub chunk_count: u64,
+
+    /// [`ChunkConfig::fingerprint`](crate::ChunkConfig::fingerprint) of the
+    /// configuration the checkpoint was taken with.
+    pub config_fingerprint: u64,
+
+    /// Number of bytes after `offset` that were pushed but not yet chunked.
+    pub pending_len: u64,
+}
+
+impl Checkpoint {
+    /// Current encoding version.
+    pub const VERSION: u8 = 1;
+
+    /// Encodes the checkpoint.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(ENCODED_LEN);
+        out.push(Self::VERSION);
+        for field in [
+            self.rolling_hash,
+            self.bytes_since_boundary,
+            self.offset,
+            self.chunk_count,
+            self.config_fingerprint,
+            self.pending_len,
+        ] {
+            out.extend_from_slice(&field.to_le_bytes());
+        }
+        out
+    }
+
+    /// Decodes a checkpoint.
+    ///
+    /// Returns `None` for an unknown version or a wrong length.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != ENCODED_LEN || bytes[0] != Self::VERSION {
+            return None;
+        }
+        let mut fields = bytes[1..].chunks_exact(8).map(|field| {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(field);
+            u64::from_le_bytes(buf)
+        });
+        let mut next = || fields.next().unwrap_or_default();
+        Some(Self {
+            rolling_hash: next(),
+            bytes_since_boundary: next(),
+            offset: next(),
+            chunk_count: next(),
+            config_fingerprint: next(),
+            pending_len: next(),
+        })
+    }
+}
+
+/// Checks that `checkpoint` can be restored with `config` and the re-read
+/// `pending` bytes, and returns the CDC state after them.
+///
+/// The rolling hash carries across boundaries, so it also depends on bytes
+/// before `pending`. Those are shifted out after 64 bytes, though: once at
+/// least [`REPLAY_CHECK_LEN`] bytes are pending, replaying them from a zero
+/// state must reproduce the checkpoint's hash. Only the last 64 or so bytes
+/// determine it, and shorter pending bytes cannot be checked against it.
+pub fn restore_cdc(
+    config: &ChunkConfig,
+    checkpoint: &Checkpoint,
+    pending: &[u8],
+) -> Result<FastCdc, ChunkError> {
+    if checkpoint.config_fingerprint != config.fingerprint() {
+        return Err(ChunkError::InvalidConfig {
+            message: "checkpoint was taken with a different configuration",
+        });
+    }
+    if config.hash_config().stream_digest() {
+        return Err(ChunkError::InvalidConfig {
+            message: "stream digest cannot be resumed from a checkpoint",
+        });
+    }
+    if pending.len() as u64 != checkpoint.pending_len {
+        return Err(ChunkError::InvalidInput {
+            message: "pending bytes do not match checkpoint",
+        });
+    }
+    if checkpoint.pending_len != checkpoint.bytes_since_boundary {
+        return Err(ChunkError::InvalidInput {
+            message: "checkpoint pending length does not match its rolling state",
+        });
+    }
+
+    let mut cdc = FastCdc::from_config(config);
+    if pending.len() >= REPLAY_CHECK_LEN && cdc.hash_of(pending) != checkpoint.rolling_hash {
+        return Err(ChunkError::InvalidInput {
+            message: "pending bytes do not match checkpoint rolling state",
+        });
+    }
+    cdc.set_state(checkpoint.rolling_hash, pending.len());
+    Ok(cdc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let checkpoint = Checkpoint {
+            rolling_hash: 0xDEAD_BEEF,
+            bytes_since_boundary: 17,
+            offset: 1 << 40,
+            chunk_count: 12345,
+            config_fingerprint: 42,
+            pending_len: 17,
+        };
+        let bytes = checkpoint.to_bytes();
+        assert_eq!(bytes.len(), ENCODED_LEN);
+        assert_eq!(bytes[0], Checkpoint::VERSION);
+        assert_eq!(Checkpoint::from_bytes(&bytes), Some(checkpoint));
+    }
+
+    #[test]
+    fn test_rejects_unknown_version_and_length() {
+        let mut bytes = Checkpoint {
+            rolling_hash: 1,
+            bytes_since_boundary: 2,
+            offset: 3,
+            chunk_count: 4,
+            config_fingerprint: 5,
+            pending_len: 6,
+        }
+        .to_bytes();
+        assert!(Checkpoint::from_bytes(&bytes[..ENCODED_LEN - 1]).is_none());
+        bytes[0] = 2;
+        assert!(Checkpoint::from_bytes(&bytes).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_checkpoint_serde() {
+        let checkpoint = Checkpoint {
+            rolling_hash: u64::MAX,
+            bytes_since_boundary: 17,
+            offset: 1 << 40,
+            chunk_count: 3,
+            config_fingerprint: 42,
+            pending_len: 17,
+        };
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Checkpoint>(&json).unwrap(),
+            checkpoint
+        );
+    }
+}
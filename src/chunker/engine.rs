@@ -1,7 +1,7 @@
 //! Core chunking engine - Chunker with streaming API.
 //!
 //! - [`Chunker`] - Stateful CDC engine
-//! - `push()` - Feed data in any size
+//! - `push_buf()` / `push_segments()` - Feed data in any size
 //! - `finish()` - Flush remaining data
 //!
 //! # Example
@@ -13,26 +13,38 @@
 //! let config = ChunkConfig::default();
 //! let mut chunker = Chunker::new(config);
 //!
-//! let chunks1 = chunker.push(Bytes::from(&b"first"[..]));
-//! let chunks2 = chunker.push(Bytes::from(&b"second"[..]));
+//! let chunks1 = chunker.push_buf(Bytes::from(&b"first"[..]));
+//! let chunks2 = chunker.push_buf(Bytes::from(&b"second"[..]));
 //! let final_chunk = chunker.finish();
 //! # Ok::<(), chunkrs::ChunkError>(())
 //! ```
 
-use bytes::Bytes;
+use bytes::{Buf, Bytes};
 
 use crate::cdc::FastCdc;
-use crate::chunk::Chunk;
+use crate::chunk::{Chunk, ChunkHash};
 use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+use crate::hash::{ChunkHasher, RollingChecksum};
+
+use super::checkpoint::restore_cdc;
+use super::{Checkpoint, StreamSummary};
 
 /// A chunker that processes streaming byte data into content-defined chunks.
 ///
+/// For a single stream, prefer [`ChunkSession`](crate::ChunkSession), whose
+/// consuming `finish()` rules out pushing after the end of the stream.
+///
 /// # Streaming API
 ///
-/// - Call `push()` with data in any size
-/// - Returns complete chunks and unprocessed bytes
-/// - Feed unprocessed bytes back in the next `push()` call
-/// - Call `finish()` when stream ends
+/// - Call `push_buf()` or `push_segments()` with data in any size
+/// - Returns complete chunks; incomplete data stays pending inside the chunker
+/// - Call `finish()` or `finish_with_summary()` when the stream ends; data
+///   pushed afterwards is a new stream, continuing at the current offset or,
+///   after the latter, starting again at the base offset
+///
+/// The deprecated `push()` also returns a copy of the pending bytes. They are
+/// informational only and must not be pushed again.
 ///
 /// # Determinism
 ///
@@ -59,20 +71,13 @@ use crate::config::ChunkConfig;
 ///     Bytes::from(&b" final part"[..]),
 /// ];
 ///
-/// let mut all_chunks = Vec::new();
-/// let mut pending = Bytes::new();
+/// let mut all_chunks = chunker.push_segments(data);
 ///
-/// for chunk in data {
-///     let (chunks, leftover) = chunker.push(chunk);
-///     all_chunks.extend(chunks);
-///     pending = leftover;
-/// }
-///
-/// if let Some(final_chunk) = chunker.finish() {
-///     all_chunks.push(final_chunk);
-/// }
+/// let (final_chunk, summary) = chunker.finish_with_summary();
+/// all_chunks.extend(final_chunk);
 ///
 /// println!("Produced {} chunks", all_chunks.len());
+/// assert_eq!(summary.len, 33);
 /// # Ok::<(), chunkrs::ChunkError>(())
 /// ```
 #[derive(Debug)]
@@ -80,92 +85,266 @@ pub struct Chunker {
     cdc: FastCdc,
     pending: Option<Bytes>,
     offset: u64,
+    /// Offset [`reset`](Chunker::reset) returns to; see [`Chunker::with_offset`].
+    base_offset: u64,
+    /// Offset the current stream started at.
+    stream_start: u64,
     config: ChunkConfig,
+    hasher: Option<Box<dyn ChunkHasher>>,
+    stream_hasher: Option<Box<dyn ChunkHasher>>,
+    chunk_count: u64,
 }
 
 impl Chunker {
     /// Creates a new chunker with the given configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`ChunkConfig::validate`] fails: sizes out of order, or
+    /// hashing or the stream digest enabled with a hash configuration that
+    /// cannot build a hasher. Every constructor that takes a `ChunkConfig`,
+    /// here and in the I/O adapters, panics the same way and has a `try_`
+    /// variant returning the error instead; use those for configurations from
+    /// untrusted or deserialized sources.
     pub fn new(config: ChunkConfig) -> Self {
-        #[cfg(feature = "keyed-cdc")]
-        let key = config.keyed_gear_table_key();
-        #[cfg(not(feature = "keyed-cdc"))]
-        let key = None;
+        Self::try_new(config).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a new chunker, rejecting an invalid configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChunkError::InvalidConfig`] if
+    /// [`ChunkConfig::validate`] fails, for example for a keyed hash
+    /// configuration without its key or an algorithm not compiled in.
+    pub fn try_new(config: ChunkConfig) -> Result<Self, ChunkError> {
+        config.validate()?;
+        Ok(Self::build(config))
+    }
 
+    /// Creates a chunker for a configuration that has been checked.
+    fn build(config: ChunkConfig) -> Self {
         Self {
-            cdc: FastCdc::with_key(
-                config.min_size(),
-                config.avg_size(),
-                config.max_size(),
-                config.normalization_level(),
-                key,
-            ),
+            cdc: FastCdc::from_config(&config),
             pending: None,
             offset: 0,
+            base_offset: 0,
+            stream_start: 0,
+            hasher: config.hash_config().build_hasher(),
+            stream_hasher: config.hash_config().build_stream_hasher(),
+            chunk_count: 0,
             config,
         }
     }
 
-    /// Computes hash for the given data if hashing is enabled.
-    fn compute_hash(&self, _data: &[u8]) -> Option<crate::chunk::ChunkHash> {
-        if !self.config.hash_config().enabled {
-            return None;
+    /// Creates a new chunker whose stream starts at `offset`.
+    ///
+    /// The offset is treated as a fresh chunk boundary: emitted chunks carry
+    /// offsets relative to the original stream. The rolling hash starts from
+    /// zero, while chunking the whole stream carries it over from the
+    /// previous chunk; the difference is shifted out within about 64 bytes,
+    /// so with a `min_size` of 128 bytes or more, starting at a real boundary
+    /// yields the same chunks as chunking the whole stream.
+    ///
+    /// [`StreamSummary::len`] counts only the bytes pushed after `offset`, and
+    /// [`reset`](Self::reset) returns to `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Like [`Chunker::new`].
+    pub fn with_offset(config: ChunkConfig, offset: u64) -> Self {
+        let mut chunker = Self::new(config);
+        chunker.reset_to(offset);
+        chunker
+    }
+
+    /// Creates a new chunker whose stream starts at `offset`, rejecting an
+    /// invalid configuration.
+    ///
+    /// # Errors
+    ///
+    /// Like [`Chunker::try_new`].
+    pub fn try_with_offset(config: ChunkConfig, offset: u64) -> Result<Self, ChunkError> {
+        let mut chunker = Self::try_new(config)?;
+        chunker.reset_to(offset);
+        Ok(chunker)
+    }
+
+    /// Restores a chunker from a [`Checkpoint`].
+    ///
+    /// `pending` must be the `checkpoint.pending_len` bytes of the stream
+    /// starting at `checkpoint.offset`, re-read from the source; the next
+    /// `push()` continues with the byte after them. The resumed stream
+    /// produces the same chunks as an uninterrupted run. The restored stream
+    /// is taken to start at offset 0, so [`StreamSummary::len`] ends up as
+    /// the total stream length.
+    ///
+    /// # Errors
+    ///
+    /// - [`ChunkError::InvalidConfig`] if `config` differs from the one the
+    ///   checkpoint was taken with, enables the stream digest (hasher state
+    ///   is not checkpointed) or fails [`ChunkConfig::validate`]
+    /// - [`ChunkError::InvalidInput`] if `pending` has the wrong length, the
+    ///   checkpoint is inconsistent, or `pending` does not reproduce the
+    ///   checkpoint's rolling hash (wrong bytes were re-read; checked once at
+    ///   least 128 bytes are pending)
+    pub fn restore(
+        config: ChunkConfig,
+        checkpoint: &Checkpoint,
+        pending: Bytes,
+    ) -> Result<Self, ChunkError> {
+        let cdc = restore_cdc(&config, checkpoint, &pending)?;
+
+        let mut chunker = Self::try_new(config)?;
+        chunker.cdc = cdc;
+        chunker.offset = checkpoint.offset;
+        chunker.chunk_count = checkpoint.chunk_count;
+        chunker.pending = (!pending.is_empty()).then_some(pending);
+        Ok(chunker)
+    }
+
+    /// Captures the chunker's position for [`Chunker::restore`].
+    ///
+    /// The caller keeps (or can re-read) the `pending_len` bytes starting at
+    /// `offset`.
+    pub fn checkpoint(&self) -> Checkpoint {
+        let (rolling_hash, bytes_since_boundary) = self.cdc.state();
+        Checkpoint {
+            rolling_hash,
+            bytes_since_boundary: bytes_since_boundary as u64,
+            offset: self.offset,
+            chunk_count: self.chunk_count,
+            config_fingerprint: self.config.fingerprint(),
+            pending_len: self.pending_len() as u64,
         }
-        #[cfg(feature = "hash-blake3")]
-        return Some(crate::hash::Blake3Hasher::hash(_data));
-        #[cfg(not(feature = "hash-blake3"))]
-        return None;
+    }
+
+    /// Computes hash for the given data if hashing is enabled.
+    fn compute_hash(&mut self, data: &[u8]) -> Option<ChunkHash> {
+        self.hasher.as_mut().map(|hasher| hasher.digest(data))
     }
 
     /// Creates a new Chunk with the given data, offset, and hash.
-    fn create_chunk(&self, data: Bytes, offset: u64) -> Chunk {
+    fn create_chunk(&mut self, data: Bytes, offset: u64) -> Chunk {
+        #[cfg(feature = "bao")]
+        let (hash, outboard) = match crate::config::encode_bao(self.config.hash_config(), &data) {
+            Some((hash, outboard)) => (Some(hash), Some(outboard)),
+            None => (self.compute_hash(data.as_ref()), None),
+        };
+        #[cfg(not(feature = "bao"))]
         let hash = self.compute_hash(data.as_ref());
+        let weak_hash = self
+            .config
+            .hash_config()
+            .weak_checksum()
+            .then(|| RollingChecksum::checksum(&data));
+        self.chunk_count += 1;
         Chunk {
             data,
             offset: Some(offset),
             hash,
+            weak_hash,
+            #[cfg(feature = "bao")]
+            outboard,
         }
     }
 
     /// Pushes data into the chunker and returns complete chunks.
     ///
-    /// Returns `(Vec<Chunk>, Bytes)` where the second element must be fed back in the next call.
+    /// Returns `(Vec<Chunk>, Bytes)` where the second element is a copy of the
+    /// bytes still pending inside the chunker. It is informational only and
+    /// must not be pushed again.
+    #[deprecated(
+        note = "use `ChunkSession::push` or `Chunker::push_buf`, which return only chunks"
+    )]
     pub fn push(&mut self, data: Bytes) -> (Vec<Chunk>, Bytes) {
+        let chunks = self.push_bytes(data);
+        (chunks, self.pending.clone().unwrap_or_default())
+    }
+
+    /// Scans `data` and returns the chunks it completes.
+    fn push_bytes(&mut self, mut data: Bytes) -> Vec<Chunk> {
         let mut chunks = Vec::new();
-        let mut new_chunk_start = 0;
-
-        for (i, &byte) in data.iter().enumerate() {
-            if self.cdc.update(byte) {
-                let chunk_data = if let Some(ref pending) = self.pending {
-                    crate::util::combine_bytes(pending, &data[new_chunk_start..=i])
-                } else {
-                    data.slice(new_chunk_start..=i)
-                };
-
-                let chunk_offset = self.offset;
-                chunks.push(self.create_chunk(chunk_data, chunk_offset));
-
-                let chunk_len =
-                    self.pending.as_ref().map_or(0, |p| p.len()) + (i + 1 - new_chunk_start);
-                self.offset += chunk_len as u64;
-                new_chunk_start = i + 1;
-                self.pending = None;
-            }
+        while let Some(chunk) = self.scan(&mut data) {
+            chunks.push(chunk);
         }
+        chunks
+    }
 
-        if new_chunk_start < data.len() {
-            let remaining = data.slice(new_chunk_start..);
-            if let Some(pending) = self.pending.take() {
-                self.pending = Some(crate::util::combine_bytes(&pending, remaining.as_ref()));
-            } else {
-                self.pending = Some(remaining);
+    /// Scans `data` up to the next boundary and returns the chunk ending
+    /// there, leaving the rest in `data`.
+    ///
+    /// Without a boundary, all of `data` becomes pending.
+    fn scan(&mut self, data: &mut Bytes) -> Option<Chunk> {
+        match scan_boundary(self, data) {
+            Some(end) => Some(emit_scanned(self, data.split_to(end))),
+            None => {
+                hold_scanned(self, std::mem::take(data));
+                None
             }
         }
+    }
 
-        (chunks, self.pending.clone().unwrap_or_default())
+    /// Pushes every segment of a [`Buf`] and returns complete chunks.
+    ///
+    /// Each contiguous segment is scanned in place, without flattening the
+    /// buffer first. Segments are taken with [`Buf::copy_to_bytes`], which is
+    /// zero-copy for `Bytes`, `BytesMut` and chains of them, so chunks lying
+    /// within one segment share its memory; only chunks spanning segments are
+    /// copied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::{Buf, Bytes};
+    /// use chunkrs::{ChunkConfig, Chunker};
+    ///
+    /// let mut chunker = Chunker::new(ChunkConfig::new(4, 16, 64)?);
+    /// let header = Bytes::from(vec![1u8; 100]);
+    /// let body = Bytes::from(vec![2u8; 400]);
+    ///
+    /// let mut chunks = chunker.push_buf(header.chain(body));
+    /// chunks.extend(chunker.finish());
+    /// assert_eq!(chunks.iter().map(|c| c.len()).sum::<usize>(), 500);
+    /// # Ok::<(), chunkrs::ChunkError>(())
+    /// ```
+    pub fn push_buf(&mut self, mut buf: impl Buf) -> Vec<Chunk> {
+        let mut chunks = Vec::new();
+        while buf.has_remaining() {
+            let segment = buf.copy_to_bytes(buf.chunk().len());
+            chunks.extend(self.push_bytes(segment));
+        }
+        chunks
+    }
+
+    /// Pushes a sequence of segments and returns complete chunks.
+    ///
+    /// Accepts `VecDeque<Bytes>`, `Vec<Bytes>` or any iterator of `Bytes`.
+    /// As with [`push_buf`](Self::push_buf), chunks lying within one segment
+    /// are zero-copy slices of it.
+    pub fn push_segments(&mut self, segments: impl IntoIterator<Item = Bytes>) -> Vec<Chunk> {
+        let mut chunks = Vec::new();
+        for segment in segments {
+            chunks.extend(self.push_bytes(segment));
+        }
+        chunks
     }
 
-    /// Finalizes the chunker and returns the final chunk if any.
+    /// Finalizes the stream and returns the final chunk if any.
+    ///
+    /// The stream ends here: data pushed afterwards is a new stream, with its
+    /// own rolling state, chunk count and stream digest, continuing at the
+    /// current offset. [`reset`](Self::reset) still returns to the base
+    /// offset. Prefer [`ChunkSession`](crate::ChunkSession), which cannot be
+    /// pushed to after the end of its stream.
     pub fn finish(&mut self) -> Option<Chunk> {
+        let final_chunk = self.take_final_chunk();
+        self.start_stream();
+        final_chunk
+    }
+
+    /// Emits the pending bytes as the final chunk, leaving the stream open.
+    fn take_final_chunk(&mut self) -> Option<Chunk> {
         if let Some(pending) = self.pending.take() {
             if pending.is_empty() {
                 return None;
@@ -181,11 +360,55 @@ impl Chunker {
         }
     }
 
+    /// Finalizes the stream and returns the final chunk with a [`StreamSummary`].
+    ///
+    /// The summary reports the total length, the number of chunks emitted
+    /// since the stream started, and the whole-stream digest if enabled with
+    /// [`HashConfig::with_stream_digest`](crate::HashConfig::with_stream_digest).
+    /// The digest is computed incrementally during `push()` and does not
+    /// depend on how the input was split.
+    ///
+    /// The length counts from the offset the stream started at: the base
+    /// offset (see [`with_offset`](Self::with_offset)) or the end of the
+    /// stream before it. The chunker is [`reset`](Self::reset) afterwards.
+    pub fn finish_with_summary(&mut self) -> (Option<Chunk>, StreamSummary) {
+        let final_chunk = self.take_final_chunk();
+        let summary = StreamSummary {
+            len: self.offset - self.stream_start,
+            chunk_count: self.chunk_count,
+            digest: self.stream_hasher.as_ref().map(|h| h.finalize()),
+        };
+        self.reset();
+        (final_chunk, summary)
+    }
+
     /// Resets the chunker state for a new stream.
+    ///
+    /// The new stream starts at the offset the chunker was created with
+    /// ([`with_offset`](Self::with_offset)) or last reset to, 0 by default.
     pub fn reset(&mut self) {
-        self.cdc.reset();
+        self.reset_to(self.base_offset);
+    }
+
+    /// Resets the chunker state for a new stream starting at `offset`.
+    ///
+    /// Like [`Chunker::with_offset`], the offset is treated as a fresh chunk
+    /// boundary.
+    pub fn reset_to(&mut self, offset: u64) {
         self.pending = None;
-        self.offset = 0;
+        self.offset = offset;
+        self.base_offset = offset;
+        self.start_stream();
+    }
+
+    /// Starts a new stream at the current offset.
+    fn start_stream(&mut self) {
+        self.cdc.reset();
+        self.stream_start = self.offset;
+        self.chunk_count = 0;
+        if let Some(stream_hasher) = self.stream_hasher.as_mut() {
+            stream_hasher.reset();
+        }
     }
 
     /// Returns the current offset in the stream.
@@ -202,6 +425,95 @@ impl Chunker {
     pub fn config(&self) -> &ChunkConfig {
         &self.config
     }
+
+    /// Wraps this chunker in a [`FramedRead`] over a tokio reader (requires
+    /// `tokio` feature).
+    ///
+    /// The result is a `Stream` of `Result<Chunk, ChunkError>`; see
+    /// [`ChunkDecoder`](crate::ChunkDecoder).
+    ///
+    /// [`FramedRead`]: tokio_util::codec::FramedRead
+    #[cfg(feature = "tokio")]
+    pub fn into_stream<R: tokio::io::AsyncRead>(
+        self,
+        reader: R,
+    ) -> tokio_util::codec::FramedRead<R, crate::ChunkDecoder> {
+        tokio_util::codec::FramedRead::new(reader, crate::ChunkDecoder::from(self))
+    }
+}
+
+/// Feeds `data` to the rolling hash up to the next boundary and returns the
+/// length of the prefix ending there, if any.
+///
+/// The bytes fed also go into the stream digest. The caller keeps them and
+/// hands them to [`emit_scanned`] or [`hold_scanned`].
+pub fn scan_boundary(chunker: &mut Chunker, data: &[u8]) -> Option<usize> {
+    let end = data
+        .iter()
+        .position(|&byte| chunker.cdc.update(byte))
+        .map(|i| i + 1);
+    if let Some(stream_hasher) = chunker.stream_hasher.as_mut() {
+        stream_hasher.update(&data[..end.unwrap_or(data.len())]);
+    }
+    end
+}
+
+/// Emits the chunk formed by the pending bytes followed by `data`, which
+/// [`scan_boundary`] found to end at a boundary.
+pub fn emit_scanned(chunker: &mut Chunker, data: Bytes) -> Chunk {
+    let chunk_data = match chunker.pending.take() {
+        Some(pending) => crate::util::combine_bytes(&pending, &data),
+        None => data,
+    };
+    let chunk_offset = chunker.offset;
+    let chunk = chunker.create_chunk(chunk_data, chunk_offset);
+    chunker.offset += chunk.len() as u64;
+    chunk
+}
+
+/// Appends bytes in which [`scan_boundary`] found no boundary to the pending
+/// bytes.
+pub fn hold_scanned(chunker: &mut Chunker, data: Bytes) {
+    if data.is_empty() {
+        return;
+    }
+    chunker.pending = Some(match chunker.pending.take() {
+        Some(pending) => crate::util::combine_bytes(&pending, &data),
+        None => data,
+    });
+}
+
+/// Emits the next chunk of `data`, the unscanned rest of a stream, and
+/// advances `data` past it, finishing the stream once `data` runs out.
+///
+/// This is [`Chunker::push_buf`] followed by [`Chunker::finish`], one chunk
+/// at a time: nothing is scanned before it is asked for, and chunks are
+/// zero-copy slices of `data`.
+#[cfg(feature = "mmap")]
+pub fn next_chunk(chunker: &mut Chunker, data: &mut Bytes) -> Option<Chunk> {
+    chunker.scan(data).or_else(|| chunker.finish())
+}
+
+/// Forks the chunker at its current position.
+///
+/// The clone shares the pending bytes, configuration and gear table, and
+/// continues exactly as the original would: feeding both the same data yields
+/// the same chunks, so two continuations of one prefix can be compared.
+/// Hashers are copied with their state by [`ChunkHasher::fork`].
+impl Clone for Chunker {
+    fn clone(&self) -> Self {
+        Self {
+            cdc: self.cdc.clone(),
+            pending: self.pending.clone(),
+            offset: self.offset,
+            base_offset: self.base_offset,
+            stream_start: self.stream_start,
+            config: self.config,
+            hasher: self.hasher.as_ref().map(|hasher| hasher.fork()),
+            stream_hasher: self.stream_hasher.as_ref().map(|hasher| hasher.fork()),
+            chunk_count: self.chunk_count,
+        }
+    }
 }
 
 impl Default for Chunker {
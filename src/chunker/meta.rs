@@ -0,0 +1,311 @@
+//! Metadata-only chunking.
+
+use crate::cdc::FastCdc;
+use crate::chunk::ChunkMeta;
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+use crate::hash::{ChunkHasher, RollingChecksum};
+
+use super::checkpoint::restore_cdc;
+use super::{Checkpoint, StreamSummary};
+
+/// A chunker that emits [`ChunkMeta`] instead of [`Chunk`](crate::Chunk).
+///
+/// For indexing jobs that only need `(offset, len, hash)`. Hashes are computed
+/// incrementally as data arrives, so the chunker keeps no data between calls
+/// and the input is not retained by the result, however many chunks are in
+/// flight. Boundaries and hashes are those [`Chunker`](crate::Chunker)
+/// produces for the same configuration.
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::{ChunkConfig, MetaChunker};
+///
+/// let mut chunker = MetaChunker::new(ChunkConfig::new(4, 16, 64)?);
+/// let mut index = chunker.push(&[7u8; 300]);
+/// index.extend(chunker.finish());
+///
+/// assert_eq!(index.iter().map(|m| m.len).sum::<u64>(), 300);
+/// # Ok::<(), chunkrs::ChunkError>(())
+/// ```
+#[derive(Debug)]
+pub struct MetaChunker {
+    cdc: FastCdc,
+    offset: u64,
+    /// Offset [`reset`](MetaChunker::reset) returns to.
+    base_offset: u64,
+    /// Offset the current stream started at.
+    stream_start: u64,
+    config: ChunkConfig,
+    hasher: Option<Box<dyn ChunkHasher>>,
+    stream_hasher: Option<Box<dyn ChunkHasher>>,
+    chunk_count: u64,
+    /// Bytes of the current chunk hashed so far.
+    chunk_len: u64,
+    /// Running weak checksum of the current chunk.
+    weak: u32,
+}
+
+impl MetaChunker {
+    /// Creates a new metadata chunker with the given configuration.
+    ///
+    /// # Panics
+    ///
+    /// Like [`Chunker::new`](crate::Chunker::new); use
+    /// [`MetaChunker::try_new`] to get an error instead.
+    pub fn new(config: ChunkConfig) -> Self {
+        Self::try_new(config).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a new metadata chunker, rejecting an invalid configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChunkError::InvalidConfig`] if [`ChunkConfig::validate`]
+    /// fails.
+    pub fn try_new(config: ChunkConfig) -> Result<Self, ChunkError> {
+        config.validate()?;
+        Ok(Self::build(config))
+    }
+
+    /// Creates a metadata chunker for a configuration that has been checked.
+    fn build(config: ChunkConfig) -> Self {
+        Self {
+            cdc: FastCdc::from_config(&config),
+            offset: 0,
+            base_offset: 0,
+            stream_start: 0,
+            hasher: config.hash_config().build_hasher(),
+            stream_hasher: config.hash_config().build_stream_hasher(),
+            chunk_count: 0,
+            chunk_len: 0,
+            weak: 1,
+            config,
+        }
+    }
+
+    /// Creates a metadata chunker whose stream starts at `offset`.
+    ///
+    /// See [`Chunker::with_offset`](crate::Chunker::with_offset): the summary
+    /// length counts from `offset`, and [`reset`](Self::reset) returns to it.
+    ///
+    /// # Panics
+    ///
+    /// Like [`Chunker::new`](crate::Chunker::new).
+    pub fn with_offset(config: ChunkConfig, offset: u64) -> Self {
+        let mut chunker = Self::new(config);
+        chunker.reset_to(offset);
+        chunker
+    }
+
+    /// Creates a metadata chunker whose stream starts at `offset`, rejecting
+    /// an invalid configuration.
+    ///
+    /// # Errors
+    ///
+    /// Like [`MetaChunker::try_new`].
+    pub fn try_with_offset(config: ChunkConfig, offset: u64) -> Result<Self, ChunkError> {
+        let mut chunker = Self::try_new(config)?;
+        chunker.reset_to(offset);
+        Ok(chunker)
+    }
+
+    /// Restores a metadata chunker from a [`Checkpoint`].
+    ///
+    /// `pending` must be the `checkpoint.pending_len` bytes of the stream
+    /// starting at `checkpoint.offset`; they are hashed again, but not kept.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Chunker::restore`](crate::Chunker::restore).
+    pub fn restore(
+        config: ChunkConfig,
+        checkpoint: &Checkpoint,
+        pending: &[u8],
+    ) -> Result<Self, ChunkError> {
+        let cdc = restore_cdc(&config, checkpoint, pending)?;
+
+        let mut chunker = Self::try_new(config)?;
+        chunker.cdc = cdc;
+        chunker.offset = checkpoint.offset;
+        chunker.chunk_count = checkpoint.chunk_count;
+        chunker.feed(pending);
+        Ok(chunker)
+    }
+
+    /// Captures the chunker's position for [`MetaChunker::restore`].
+    ///
+    /// The caller keeps (or can re-read) the `pending_len` bytes starting at
+    /// `offset`.
+    pub fn checkpoint(&self) -> Checkpoint {
+        let (rolling_hash, bytes_since_boundary) = self.cdc.state();
+        Checkpoint {
+            rolling_hash,
+            bytes_since_boundary: bytes_since_boundary as u64,
+            offset: self.offset,
+            chunk_count: self.chunk_count,
+            config_fingerprint: self.config.fingerprint(),
+            pending_len: self.chunk_len,
+        }
+    }
+
+    /// Pushes data and returns metadata for the completed chunks.
+    pub fn push(&mut self, data: &[u8]) -> Vec<ChunkMeta> {
+        let mut metas = Vec::new();
+        let mut chunk_start = 0;
+
+        if let Some(stream_hasher) = self.stream_hasher.as_mut() {
+            stream_hasher.update(data);
+        }
+
+        for (i, &byte) in data.iter().enumerate() {
+            if self.cdc.update(byte) {
+                self.feed(&data[chunk_start..=i]);
+                metas.push(self.emit());
+                chunk_start = i + 1;
+            }
+        }
+        self.feed(&data[chunk_start..]);
+
+        metas
+    }
+
+    /// Finalizes the stream and returns the metadata of the final chunk, if
+    /// any.
+    ///
+    /// As with [`Chunker::finish`](crate::Chunker::finish), data pushed
+    /// afterwards is a new stream continuing at the current offset.
+    pub fn finish(&mut self) -> Option<ChunkMeta> {
+        let final_meta = self.take_final_meta();
+        self.start_stream();
+        final_meta
+    }
+
+    /// Emits the metadata of the final chunk, leaving the stream open.
+    fn take_final_meta(&mut self) -> Option<ChunkMeta> {
+        (self.chunk_len > 0).then(|| self.emit())
+    }
+
+    /// Finalizes the stream and returns the final chunk's metadata with a
+    /// [`StreamSummary`].
+    ///
+    /// As with [`Chunker::finish_with_summary`](crate::Chunker::finish_with_summary),
+    /// the length counts from the offset the stream started at, and the
+    /// chunker is [`reset`](Self::reset) afterwards.
+    pub fn finish_with_summary(&mut self) -> (Option<ChunkMeta>, StreamSummary) {
+        let final_meta = self.take_final_meta();
+        let summary = StreamSummary {
+            len: self.offset - self.stream_start,
+            chunk_count: self.chunk_count,
+            digest: self.stream_hasher.as_ref().map(|h| h.finalize()),
+        };
+        self.reset();
+        (final_meta, summary)
+    }
+
+    /// Resets the chunker state for a new stream.
+    ///
+    /// The new stream starts at the offset the chunker was created with
+    /// ([`with_offset`](Self::with_offset)) or last reset to, 0 by default.
+    pub fn reset(&mut self) {
+        self.reset_to(self.base_offset);
+    }
+
+    /// Resets the chunker state for a new stream starting at `offset`.
+    pub fn reset_to(&mut self, offset: u64) {
+        self.offset = offset;
+        self.base_offset = offset;
+        self.chunk_len = 0;
+        self.weak = 1;
+        self.start_stream();
+    }
+
+    /// Starts a new stream at the current offset.
+    fn start_stream(&mut self) {
+        self.cdc.reset();
+        self.stream_start = self.offset;
+        self.chunk_count = 0;
+        if let Some(stream_hasher) = self.stream_hasher.as_mut() {
+            stream_hasher.reset();
+        }
+    }
+
+    /// Returns the current offset in the stream (the end of the last chunk).
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the number of bytes of the current chunk pushed so far.
+    pub fn pending_len(&self) -> u64 {
+        self.chunk_len
+    }
+
+    /// Returns the configuration.
+    pub fn config(&self) -> &ChunkConfig {
+        &self.config
+    }
+
+    /// Adds bytes of the current chunk to the incremental hash state.
+    fn feed(&mut self, data: &[u8]) {
+        if data.is_empty() {
+            return;
+        }
+        if self.chunk_len == 0 {
+            if let Some(hasher) = self.hasher.as_mut() {
+                hasher.reset();
+            }
+            self.weak = 1;
+        }
+        if let Some(hasher) = self.hasher.as_mut() {
+            hasher.update(data);
+        }
+        if self.config.hash_config().weak_checksum() {
+            self.weak = RollingChecksum::extend(self.weak, data);
+        }
+        self.chunk_len += data.len() as u64;
+    }
+
+    /// Emits the metadata of the current chunk and starts the next one.
+    fn emit(&mut self) -> ChunkMeta {
+        let meta = ChunkMeta {
+            offset: self.offset,
+            len: self.chunk_len,
+            hash: self.hasher.as_ref().map(|hasher| hasher.finalize()),
+            weak_hash: self
+                .config
+                .hash_config()
+                .weak_checksum()
+                .then_some(self.weak),
+        };
+        self.offset += self.chunk_len;
+        self.chunk_len = 0;
+        self.chunk_count += 1;
+        meta
+    }
+}
+
+/// Forks the chunker at its current position, including the hash state of
+/// the current chunk (see [`ChunkHasher::fork`]).
+impl Clone for MetaChunker {
+    fn clone(&self) -> Self {
+        Self {
+            cdc: self.cdc.clone(),
+            offset: self.offset,
+            base_offset: self.base_offset,
+            stream_start: self.stream_start,
+            config: self.config,
+            hasher: self.hasher.as_ref().map(|hasher| hasher.fork()),
+            stream_hasher: self.stream_hasher.as_ref().map(|hasher| hasher.fork()),
+            chunk_count: self.chunk_count,
+            chunk_len: self.chunk_len,
+            weak: self.weak,
+        }
+    }
+}
+
+impl Default for MetaChunker {
+    fn default() -> Self {
+        Self::new(ChunkConfig::default())
+    }
+}
@@ -1,8 +1,24 @@
 //! Chunking engine for processing byte streams.
 //!
-//! - [`Chunker`] - Stateful CDC engine with `push()`/`finish()` API
+//! - [`Chunker`] - Stateful CDC engine
+//! - [`ChunkSession`] - One stream with `push()` and a consuming `finish()`
+//! - [`MetaChunker`] - CDC engine emitting `ChunkMeta` without chunk data
+//! - [`Checkpoint`] - Resumable position in a stream
+//! - [`StreamSummary`] - Totals and whole-stream digest from `finish_with_summary()`
 
+mod checkpoint;
 mod engine;
+mod meta;
+mod session;
+mod summary;
 
 // Re-export for use within the crate
+pub use checkpoint::Checkpoint;
 pub use engine::Chunker;
+#[cfg(feature = "mmap")]
+pub use engine::next_chunk;
+#[cfg(feature = "tokio")]
+pub use engine::{emit_scanned, hold_scanned, scan_boundary};
+pub use meta::MetaChunker;
+pub use session::ChunkSession;
+pub use summary::StreamSummary;
@@ -0,0 +1,150 @@
+//! Single-stream chunking session with a consuming `finish()`.
+
+use bytes::{Buf, Bytes};
+
+use crate::chunk::Chunk;
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+
+use super::{Chunker, StreamSummary};
+
+/// A chunking session for exactly one byte stream.
+///
+/// `push()` returns only completed chunks; bytes that do not yet form a chunk
+/// stay inside the session and must not be fed back. `finish()` consumes the
+/// session, so data cannot be pushed after the stream has ended, and a new
+/// stream always starts from a fresh session.
+///
+/// Start a session at a base offset or from a checkpoint by converting a
+/// configured [`Chunker`] with `ChunkSession::from`.
+///
+/// # Example
+///
+/// ```
+/// use bytes::Bytes;
+/// use chunkrs::{ChunkConfig, ChunkSession};
+///
+/// let mut session = ChunkSession::new(ChunkConfig::new(4, 16, 64)?);
+///
+/// let mut chunks = Vec::new();
+/// for part in [&b"first part"[..], b" second part", b" final part"] {
+///     chunks.extend(session.push(Bytes::from(part)));
+/// }
+///
+/// let (final_chunk, summary) = session.finish();
+/// chunks.extend(final_chunk);
+/// assert_eq!(summary.len, 33);
+/// assert_eq!(chunks.iter().map(|c| c.len()).sum::<usize>(), 33);
+/// # Ok::<(), chunkrs::ChunkError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChunkSession {
+    chunker: Chunker,
+}
+
+impl ChunkSession {
+    /// Starts a session with the given configuration.
+    ///
+    /// # Panics
+    ///
+    /// Like [`Chunker::new`](crate::Chunker::new); use
+    /// [`ChunkSession::try_new`] to get an error instead.
+    pub fn new(config: ChunkConfig) -> Self {
+        Self::from(Chunker::new(config))
+    }
+
+    /// Starts a session, rejecting an invalid configuration.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Chunker::try_new`].
+    pub fn try_new(config: ChunkConfig) -> Result<Self, ChunkError> {
+        Chunker::try_new(config).map(Self::from)
+    }
+
+    /// Pushes data and returns the chunks it completes.
+    ///
+    /// Chunks lying within `data` are zero-copy slices of it.
+    pub fn push(&mut self, data: Bytes) -> Vec<Chunk> {
+        self.chunker.push_buf(data)
+    }
+
+    /// Pushes every segment of a [`Buf`] and returns the chunks they complete.
+    ///
+    /// See [`Chunker::push_buf`].
+    pub fn push_buf(&mut self, buf: impl Buf) -> Vec<Chunk> {
+        self.chunker.push_buf(buf)
+    }
+
+    /// Pushes a sequence of segments and returns the chunks they complete.
+    ///
+    /// See [`Chunker::push_segments`].
+    pub fn push_segments(&mut self, segments: impl IntoIterator<Item = Bytes>) -> Vec<Chunk> {
+        self.chunker.push_segments(segments)
+    }
+
+    /// Returns the underlying chunker, e.g. for its offset or a checkpoint.
+    pub fn chunker(&self) -> &Chunker {
+        &self.chunker
+    }
+
+    /// Ends the stream, returning the final chunk and a [`StreamSummary`].
+    pub fn finish(mut self) -> (Option<Chunk>, StreamSummary) {
+        self.chunker.finish_with_summary()
+    }
+}
+
+impl From<Chunker> for ChunkSession {
+    /// Continues the chunker's stream as a session.
+    fn from(chunker: Chunker) -> Self {
+        Self { chunker }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ChunkConfig {
+        ChunkConfig::new(16, 64, 256).unwrap()
+    }
+
+    #[test]
+    fn test_session_matches_single_push() {
+        let data: Vec<u8> = (0..3000u32).map(|i| (i * 37 % 256) as u8).collect();
+
+        let mut whole = ChunkSession::new(config());
+        let mut expected = whole.push(Bytes::from(data.clone()));
+        let (final_chunk, expected_summary) = whole.finish();
+        expected.extend(final_chunk);
+
+        let mut session = ChunkSession::new(config());
+        let mut chunks = Vec::new();
+        for part in data.chunks(77) {
+            chunks.extend(session.push(Bytes::copy_from_slice(part)));
+        }
+        let (final_chunk, summary) = session.finish();
+        chunks.extend(final_chunk);
+
+        assert_eq!(chunks.len(), expected.len());
+        for (got, want) in chunks.iter().zip(&expected) {
+            assert_eq!(
+                (got.offset, &got.data, got.hash),
+                (want.offset, &want.data, want.hash)
+            );
+        }
+        assert_eq!(summary, expected_summary);
+        assert_eq!(summary.len, 3000);
+    }
+
+    #[test]
+    fn test_session_from_chunker_keeps_offset() {
+        let mut session = ChunkSession::from(Chunker::with_offset(config(), 500));
+        assert!(session.push(Bytes::from(vec![1u8; 10])).is_empty());
+        assert_eq!(session.chunker().pending_len(), 10);
+
+        let (final_chunk, summary) = session.finish();
+        assert_eq!(final_chunk.unwrap().offset, Some(500));
+        assert_eq!(summary.len, 10);
+    }
+}
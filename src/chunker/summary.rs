@@ -0,0 +1,51 @@
+//! Summary of a completed stream.
+
+use std::fmt;
+
+use crate::chunk::ChunkHash;
+
+/// Totals for a stream, returned by
+/// [`Chunker::finish_with_summary`](crate::Chunker::finish_with_summary).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "hash-blake3")] {
+/// use chunkrs::{ChunkConfig, Chunker, HashConfig};
+/// use bytes::Bytes;
+///
+/// let config = ChunkConfig::default()
+///     .with_hash_config(HashConfig::enabled().with_stream_digest(true));
+/// let mut chunker = Chunker::new(config);
+///
+/// chunker.push_buf(Bytes::from(&b"hello "[..]));
+/// chunker.push_buf(Bytes::from(&b"world"[..]));
+/// let (_final_chunk, summary) = chunker.finish_with_summary();
+///
+/// assert_eq!(summary.len, 11);
+/// assert_eq!(summary.chunk_count, 1);
+/// assert!(summary.digest.is_some());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StreamSummary {
+    /// Total number of bytes in the stream.
+    pub len: u64,
+
+    /// Number of chunks emitted, including the final chunk.
+    pub chunk_count: u64,
+
+    /// Digest of the whole stream, if the stream digest is enabled.
+    pub digest: Option<ChunkHash>,
+}
+
+impl fmt::Display for StreamSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stream({} bytes, {} chunks", self.len, self.chunk_count)?;
+        if let Some(digest) = self.digest {
+            write!(f, ", digest={}", digest)?;
+        }
+        write!(f, ")")
+    }
+}
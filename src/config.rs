@@ -3,7 +3,12 @@
 //! - [`ChunkConfig`] - Chunk size boundaries and hashing
 //! - [`HashConfig`] - Hash computation control
 
+use std::fmt;
+use std::str::FromStr;
+
+use crate::cdc::{ALGORITHM_VERSION, GEAR_TABLE_VERSION, MASK_TABLE_VERSION, mask_bits};
 use crate::error::ChunkError;
+use crate::hash::{ChunkHasher, HashAlgorithm, HasherFactory};
 
 /// Default minimum chunk size (4 KiB).
 pub const DEFAULT_MIN_CHUNK_SIZE: usize = 4 * 1024;
@@ -19,13 +24,62 @@ pub const DEFAULT_NORMALIZATION_LEVEL: u8 = 2;
 
 /// Configuration for content-defined chunking.
 ///
-/// Size constraints: `min_size <= avg_size <= max_size`, all powers of 2.
+/// Size constraints: `0 < min_size <= avg_size <= max_size`.
 ///
 /// Normalization level controls chunk size distribution:
 /// - Level 0: Single mask
 /// - Level 1: Masks differ by ±1 bit
 /// - Level N: Masks differ by ±N bits
+///
+/// # Non-Power-of-Two Sizes
+///
+/// Sizes need not be powers of two. The boundary masks have
+/// `round(log2(avg_size))` bits (±N with normalization), so the mean chunk
+/// size follows the power of two nearest to `avg_size` rather than
+/// `avg_size` itself; `avg_size` remains the point where the stricter mask
+/// gives way to the looser one. For example, 48 KiB / 192 KiB / 768 KiB uses
+/// 18-bit masks (256 KiB) switching at 192 KiB, which pulls the mean
+/// somewhat below the 256 KiB a power-of-two average would give. `min_size`
+/// and `max_size` are applied exactly.
+///
+/// # String Form
+///
+/// [`Display`](fmt::Display) and [`FromStr`] use a compact form for command
+/// lines and config files:
+///
+/// ```text
+/// fastcdc:min=4K,avg=16K,max=64K,norm=2
+/// ```
+///
+/// Sizes take an optional binary unit: `K`/`KiB` (1024), `M`/`MiB` or
+/// `G`/`GiB`. `min`, `avg` and `max` are required; a missing `norm` takes
+/// the default [`ChunkConfig::new`] would choose. Parsing validates like
+/// `new` and reports [`ChunkError::ParseConfig`] naming the offending field.
+/// The string covers only the boundary parameters: a parsed configuration
+/// has the default [`HashConfig`] and no gear-table key.
+///
+/// ```
+/// use chunkrs::ChunkConfig;
+///
+/// let config: ChunkConfig = "fastcdc:min=48K,avg=192K,max=768K".parse()?;
+/// assert_eq!(config.avg_size(), 192 * 1024);
+/// assert_eq!(config.to_string(), "fastcdc:min=48K,avg=192K,max=768K,norm=2");
+/// # Ok::<(), chunkrs::ChunkError>(())
+/// ```
+///
+/// # Serde
+///
+/// With the `serde` feature, the sizes, normalization level and
+/// [`HashConfig`] are serialized as a struct. Deserializing validates the
+/// sizes like [`ChunkConfig::new`]; a missing normalization level takes the
+/// default `new` would choose. The `keyed-cdc` gear-table key is never
+/// serialized and must be set again after deserializing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "ChunkConfigRepr", try_from = "ChunkConfigRepr")
+)]
 pub struct ChunkConfig {
     min_size: usize,
     avg_size: usize,
@@ -39,7 +93,7 @@ pub struct ChunkConfig {
 impl ChunkConfig {
     /// Creates a new configuration.
     ///
-    /// Returns error if sizes are zero, not powers of 2, or out of order.
+    /// Returns error if sizes are zero or out of order.
     pub fn new(min_size: usize, avg_size: usize, max_size: usize) -> Result<Self, ChunkError> {
         if min_size == 0 || avg_size == 0 || max_size == 0 {
             return Err(ChunkError::InvalidConfig {
@@ -59,14 +113,7 @@ impl ChunkConfig {
             });
         }
 
-        if !min_size.is_power_of_two() || !avg_size.is_power_of_two() || !max_size.is_power_of_two()
-        {
-            return Err(ChunkError::InvalidConfig {
-                message: "chunk sizes should be powers of 2",
-            });
-        }
-
-        let avg_bits = avg_size.trailing_zeros() as u8;
+        let avg_bits = mask_bits(avg_size) as u8;
         let effective_level = DEFAULT_NORMALIZATION_LEVEL.min(avg_bits.saturating_sub(2));
 
         Ok(Self {
@@ -149,8 +196,85 @@ impl ChunkConfig {
     }
 
     /// Validates the current configuration.
+    ///
+    /// Checks the sizes like [`ChunkConfig::new`] and the hash configuration
+    /// with [`HashConfig::validate`].
     pub fn validate(&self) -> Result<(), ChunkError> {
-        Self::new(self.min_size, self.avg_size, self.max_size).map(|_| ())
+        Self::new(self.min_size, self.avg_size, self.max_size)?;
+        self.hash_config.validate()
+    }
+
+    /// Returns a stable fingerprint of everything that decides chunk
+    /// boundaries and chunk hashes.
+    ///
+    /// Two configurations with the same fingerprint cut the same input into
+    /// the same chunks with the same hashes, in any build of any crate
+    /// version that reports that fingerprint. A store can record it and
+    /// refuse writes from a mismatched writer, since their chunks would not
+    /// deduplicate.
+    ///
+    /// The fingerprint covers:
+    /// - The algorithm (FastCDC) and its version
+    /// - The gear and mask table versions
+    /// - The sizes and normalization level
+    /// - The hash algorithm, if hashing is enabled
+    /// - Identifiers of the gear-table key and the hashing key, if set
+    ///
+    /// A key identifier is a one-way keyed hash, so the fingerprint never
+    /// reveals a key. The stream digest and weak checksum settings are not
+    /// covered: they don't change chunks or their hashes.
+    ///
+    /// The value is FNV-1a over a fixed encoding and changes only when one of
+    /// the covered parameters or versions does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chunkrs::ChunkConfig;
+    ///
+    /// let config = ChunkConfig::new(4096, 16384, 65536)?;
+    /// assert_eq!(config.fingerprint(), ChunkConfig::default().fingerprint());
+    /// assert_ne!(
+    ///     config.fingerprint(),
+    ///     config.with_normalization_level(1).fingerprint()
+    /// );
+    /// # Ok::<(), chunkrs::ChunkError>(())
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut encoded = Vec::with_capacity(96);
+        encoded.extend_from_slice(b"chunkrs config fingerprint\0fastcdc");
+        encoded.extend_from_slice(&[ALGORITHM_VERSION, GEAR_TABLE_VERSION, MASK_TABLE_VERSION]);
+        for size in [self.min_size, self.avg_size, self.max_size] {
+            encoded.extend_from_slice(&(size as u64).to_le_bytes());
+        }
+        encoded.push(self.normalization_level);
+
+        encoded.push(u8::from(self.hash_config.enabled));
+        if self.hash_config.enabled {
+            encoded.extend_from_slice(&self.hash_config.algorithm.multihash_code().to_le_bytes());
+        }
+
+        // Keys require BLAKE3, so builds without it never have any.
+        #[cfg(feature = "keyed-cdc")]
+        let gear_key = self.key;
+        #[cfg(not(feature = "keyed-cdc"))]
+        let gear_key: Option<[u8; 32]> = None;
+        #[cfg(feature = "hash-blake3")]
+        let hash_key = self.hash_config.key.filter(|_| self.hash_config.enabled);
+        #[cfg(not(feature = "hash-blake3"))]
+        let hash_key: Option<[u8; 32]> = None;
+        for key in [gear_key, hash_key] {
+            encoded.push(u8::from(key.is_some()));
+            #[cfg(feature = "hash-blake3")]
+            if let Some(key) = key {
+                let key_id = blake3::keyed_hash(&key, b"chunkrs config fingerprint key id");
+                encoded.extend_from_slice(&key_id.as_bytes()[..8]);
+            }
+        }
+
+        encoded.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
     }
 }
 
@@ -170,33 +294,660 @@ impl Default for ChunkConfig {
 
 /// Configuration for chunk hashing.
 ///
-/// Controls whether BLAKE3 cryptographic hashes are computed for each chunk.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Controls whether hashes are computed for each chunk and which algorithm is
+/// used. BLAKE3 is the default; any algorithm can be plugged in through
+/// [`HashConfig::with_hasher`].
+///
+/// An enabled configuration must be able to build a hasher: the algorithm
+/// must be compiled in and keyed or custom algorithms need their key or
+/// hasher. [`HashConfig::validate`] checks this, and
+/// [`Chunker::try_new`](crate::Chunker::try_new) rejects configurations that
+/// fail it. The default enables BLAKE3 only when the `hash-blake3` feature is
+/// compiled in.
+///
+/// # Keyed Hashing
+///
+/// Unkeyed chunk hashes let anyone who knows a file confirm that a store holds
+/// it. [`HashConfig::with_key`] and [`HashConfig::with_derived_key`] switch to
+/// keyed BLAKE3 ([`HashAlgorithm::Blake3Keyed`]) so only key holders can
+/// compute chunk identities. This key is independent of the `keyed-cdc`
+/// gear-table key, which only affects boundaries.
+///
+/// The key is never printed by the `Debug` implementation.
+///
+/// # Stream Digest
+///
+/// [`HashConfig::with_stream_digest`] additionally hashes the whole stream
+/// incrementally during `push()`, reported by
+/// [`Chunker::finish_with_summary`](crate::Chunker::finish_with_summary). It
+/// uses the same algorithm (and key) as chunk hashes, and works with
+/// per-chunk hashing disabled: `HashConfig::disabled().with_stream_digest(true)`
+/// computes only the BLAKE3 digest of the whole stream.
+///
+/// # Weak Checksum
+///
+/// [`HashConfig::with_weak_checksum`] additionally computes a 32-bit Adler-32
+/// checksum for every chunk ([`Chunk::weak_hash`](crate::Chunk::weak_hash)),
+/// for rsync-style matching at arbitrary offsets with
+/// [`RollingChecksum`](crate::RollingChecksum). It is computed even when
+/// strong hashing is disabled.
+///
+/// # Bao Outboard
+///
+/// [`HashConfig::with_bao_outboard`] (requires `bao` feature) builds each
+/// chunk's [`BaoOutboard`](crate::BaoOutboard) in the same pass that computes
+/// its BLAKE3 hash and attaches it as [`Chunk::outboard`](crate::Chunk::outboard).
+/// [`BaoOutboard::for_chunk`](crate::BaoOutboard::for_chunk) builds one later
+/// instead, at the cost of hashing the data again.
+///
+/// With the `serde` feature, `enabled`, the algorithm and the stream digest,
+/// weak checksum and outboard settings are serialized. Hashing keys and custom hasher
+/// factories are not: a deserialized keyed or custom configuration fails
+/// [`HashConfig::validate`] until [`HashConfig::with_key`] or
+/// [`HashConfig::with_hasher`] is applied again.
+///
+/// Two configurations compare equal when they agree on `enabled`,
+/// [`HashConfig::algorithm`], the stream digest, weak checksum and outboard
+/// settings and the hashing key. A custom hasher counts only through its algorithm.
+#[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "HashConfigRepr", from = "HashConfigRepr")
+)]
 pub struct HashConfig {
-    /// Whether to compute BLAKE3 hashes.
+    /// Whether to compute chunk hashes.
     pub enabled: bool,
+    algorithm: HashAlgorithm,
+    stream_digest: bool,
+    weak_checksum: bool,
+    #[cfg(feature = "bao")]
+    bao_outboard: bool,
+    factory: Option<HasherFactory>,
+    #[cfg(feature = "hash-blake3")]
+    key: Option<[u8; 32]>,
 }
 
 impl HashConfig {
     /// Creates a new hash configuration.
     pub const fn new(enabled: bool) -> Self {
-        Self { enabled }
+        Self {
+            enabled,
+            algorithm: HashAlgorithm::Blake3,
+            stream_digest: false,
+            weak_checksum: false,
+            #[cfg(feature = "bao")]
+            bao_outboard: false,
+            factory: None,
+            #[cfg(feature = "hash-blake3")]
+            key: None,
+        }
     }
 
     /// Enables hashing.
     pub const fn enabled() -> Self {
-        Self { enabled: true }
+        Self::new(true)
     }
 
     /// Disables hashing.
     pub const fn disabled() -> Self {
-        Self { enabled: false }
+        Self::new(false)
+    }
+
+    /// Selects a built-in hash algorithm.
+    ///
+    /// Replaces any hasher or key previously set on this configuration.
+    /// Selecting [`HashAlgorithm::Blake3Keyed`] here leaves no key, which
+    /// [`HashConfig::validate`] rejects; use [`HashConfig::with_key`] instead.
+    pub fn with_algorithm(mut self, algorithm: HashAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self.factory = None;
+        #[cfg(feature = "hash-blake3")]
+        {
+            self.key = None;
+        }
+        self
+    }
+
+    /// Uses keyed BLAKE3 with the given 32-byte key (requires `hash-blake3` feature).
+    ///
+    /// Chunk hashes are tagged [`HashAlgorithm::Blake3Keyed`].
+    #[cfg(feature = "hash-blake3")]
+    pub fn with_key(mut self, key: [u8; 32]) -> Self {
+        self.algorithm = HashAlgorithm::Blake3Keyed;
+        self.factory = None;
+        self.key = Some(key);
+        self
+    }
+
+    /// Uses keyed BLAKE3 with a key derived from `key_material` (requires
+    /// `hash-blake3` feature).
+    ///
+    /// The key is BLAKE3's `derive_key(context, key_material)`. Using one
+    /// master secret with a distinct context per tenant (for example
+    /// `"example.com 2026-01-01 tenant-42 chunk id"`) gives each tenant
+    /// unrelated chunk identities. Contexts should be hardcoded, globally
+    /// unique strings.
+    #[cfg(feature = "hash-blake3")]
+    pub fn with_derived_key(self, context: &str, key_material: &[u8]) -> Self {
+        self.with_key(blake3::derive_key(context, key_material))
+    }
+
+    /// Returns `true` if a hashing key is configured.
+    pub fn is_keyed(&self) -> bool {
+        #[cfg(feature = "hash-blake3")]
+        return self.key.is_some();
+        #[cfg(not(feature = "hash-blake3"))]
+        return false;
+    }
+
+    /// Uses an application-supplied hasher for `algorithm`.
+    ///
+    /// The factory is called once per [`Chunker`](crate::Chunker). It is a
+    /// plain function so that the configuration stays `Copy`, and it is not
+    /// compared: `algorithm` identifies the hasher in equality and in
+    /// [`ChunkConfig::fingerprint`], so distinct hashers need distinct
+    /// [`HashAlgorithm::Custom`] codes.
+    ///
+    /// [`HashConfig::validate`] rejects a factory whose hashers report a
+    /// different [`ChunkHasher::algorithm`].
+    ///
+    /// # Panics
+    ///
+    /// Chunking panics if a hasher later produces a [`ChunkHash`](crate::ChunkHash)
+    /// tagged with another algorithm, rather than storing a mislabeled hash.
+    pub fn with_hasher(mut self, algorithm: HashAlgorithm, factory: HasherFactory) -> Self {
+        self.algorithm = algorithm;
+        self.factory = Some(factory);
+        #[cfg(feature = "hash-blake3")]
+        {
+            self.key = None;
+        }
+        self
+    }
+
+    /// Enables or disables the whole-stream digest.
+    ///
+    /// When enabled, every byte passed to `push()` is also fed to a stream
+    /// hasher, so the file digest is available without reading the data twice.
+    pub fn with_stream_digest(mut self, enabled: bool) -> Self {
+        self.stream_digest = enabled;
+        self
+    }
+
+    /// Enables or disables the per-chunk Adler-32 weak checksum.
+    pub fn with_weak_checksum(mut self, enabled: bool) -> Self {
+        self.weak_checksum = enabled;
+        self
+    }
+
+    /// Enables or disables building a Bao outboard with every chunk hash
+    /// (requires `bao` feature).
+    ///
+    /// Needs per-chunk hashing with built-in BLAKE3, keyed or not;
+    /// [`HashConfig::validate`] rejects it otherwise.
+    #[cfg(feature = "bao")]
+    pub fn with_bao_outboard(mut self, enabled: bool) -> Self {
+        self.bao_outboard = enabled;
+        self
+    }
+
+    /// Returns the configured hash algorithm.
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns `true` if the whole-stream digest is enabled.
+    pub fn stream_digest(&self) -> bool {
+        self.stream_digest
+    }
+
+    /// Returns `true` if the per-chunk weak checksum is enabled.
+    pub fn weak_checksum(&self) -> bool {
+        self.weak_checksum
+    }
+
+    /// Returns `true` if chunks carry a Bao outboard (requires `bao` feature).
+    #[cfg(feature = "bao")]
+    pub fn bao_outboard(&self) -> bool {
+        self.bao_outboard
+    }
+
+    /// Creates a hasher for this configuration.
+    ///
+    /// Returns `None` if hashing is disabled or the configuration cannot
+    /// produce a hasher (see [`HashConfig::validate`]).
+    pub fn build_hasher(&self) -> Option<Box<dyn ChunkHasher>> {
+        if !self.enabled {
+            return None;
+        }
+        self.new_hasher().ok()
+    }
+
+    /// Creates the whole-stream hasher.
+    ///
+    /// Returns `None` if the stream digest is disabled or the configuration
+    /// cannot produce a hasher. The stream digest does not depend on
+    /// `enabled`: with per-chunk hashing disabled it still uses the configured
+    /// algorithm, BLAKE3 unless another is selected.
+    pub fn build_stream_hasher(&self) -> Option<Box<dyn ChunkHasher>> {
+        if !self.stream_digest {
+            return None;
+        }
+        self.new_hasher().ok()
+    }
+
+    /// Checks that the configuration can produce the hashers it needs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChunkError::InvalidConfig`] if hashing or the stream digest
+    /// is enabled and:
+    /// - The algorithm is not compiled into this build (for example SHA-256
+    ///   without the `hash-sha256` feature)
+    /// - The algorithm is [`HashAlgorithm::Blake3Keyed`] but no key is set
+    /// - The algorithm is [`HashAlgorithm::Custom`] but no hasher is set
+    /// - The hasher set with [`HashConfig::with_hasher`] reports a different
+    ///   algorithm
+    ///
+    /// It also rejects [`HashConfig::with_bao_outboard`] unless per-chunk
+    /// hashing uses built-in BLAKE3.
+    ///
+    /// Keys and custom hashers are not serialized, so a deserialized keyed or
+    /// custom configuration fails here until they are set again.
+    pub fn validate(&self) -> Result<(), ChunkError> {
+        if self.enabled || self.stream_digest {
+            self.new_hasher()?;
+        }
+        #[cfg(feature = "bao")]
+        if self.bao_outboard
+            && !(self.enabled
+                && self.factory.is_none()
+                && matches!(
+                    self.algorithm,
+                    HashAlgorithm::Blake3 | HashAlgorithm::Blake3Keyed
+                ))
+        {
+            return Err(ChunkError::InvalidConfig {
+                message: "Bao outboards require per-chunk BLAKE3 hashing",
+            });
+        }
+        Ok(())
+    }
+
+    /// Creates a hasher for the configured algorithm, ignoring `enabled`.
+    fn new_hasher(&self) -> Result<Box<dyn ChunkHasher>, ChunkError> {
+        if let Some(factory) = self.factory {
+            let inner = factory();
+            if inner.algorithm() != self.algorithm {
+                return Err(ChunkError::InvalidConfig {
+                    message: "custom hasher reports a different algorithm than configured",
+                });
+            }
+            return Ok(Box::new(CheckedHasher { inner }));
+        }
+        match self.algorithm {
+            #[cfg(feature = "hash-blake3")]
+            HashAlgorithm::Blake3 => Ok(Box::new(crate::hash::Blake3Hasher::new())),
+            #[cfg(feature = "hash-blake3")]
+            HashAlgorithm::Blake3Keyed => self
+                .key
+                .map(|key| Box::new(crate::hash::Blake3Hasher::new_keyed(&key)) as _)
+                .ok_or(ChunkError::InvalidConfig {
+                    message: "keyed BLAKE3 requires a key (HashConfig::with_key)",
+                }),
+            #[cfg(feature = "hash-sha256")]
+            HashAlgorithm::Sha256 => Ok(Box::new(crate::hash::Sha256Hasher::new())),
+            #[cfg(feature = "hash-xxh3")]
+            HashAlgorithm::Xxh3 => Ok(Box::new(crate::hash::Xxh3Hasher::new())),
+            HashAlgorithm::Custom(_) => Err(ChunkError::InvalidConfig {
+                message: "custom hash algorithm requires a hasher (HashConfig::with_hasher)",
+            }),
+            #[allow(unreachable_patterns)]
+            _ => Err(ChunkError::InvalidConfig {
+                message: "hash algorithm is not compiled into this build",
+            }),
+        }
+    }
+}
+
+/// Hashes `data` and builds its Bao outboard in one pass, if `config` asks
+/// for outboards.
+#[cfg(feature = "bao")]
+pub fn encode_bao(
+    config: &HashConfig,
+    data: &[u8],
+) -> Option<(crate::ChunkHash, crate::BaoOutboard)> {
+    if !config.bao_outboard {
+        return None;
+    }
+    Some(match config.key {
+        Some(key) => crate::BaoOutboard::encode_keyed(&key, data),
+        None => crate::BaoOutboard::encode(data),
+    })
+}
+
+/// An application-supplied hasher whose hashes are checked to carry its
+/// algorithm tag.
+#[derive(Debug)]
+struct CheckedHasher {
+    inner: Box<dyn ChunkHasher>,
+}
+
+impl ChunkHasher for CheckedHasher {
+    fn algorithm(&self) -> HashAlgorithm {
+        self.inner.algorithm()
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    fn finalize(&self) -> crate::ChunkHash {
+        let hash = self.inner.finalize();
+        assert!(
+            hash.algorithm() == self.inner.algorithm(),
+            "custom hasher for {} produced a {} hash",
+            self.inner.algorithm(),
+            hash.algorithm()
+        );
+        hash
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    fn fork(&self) -> Box<dyn ChunkHasher> {
+        Box::new(Self {
+            inner: self.inner.fork(),
+        })
     }
 }
 
+/// BLAKE3 hashing when the `hash-blake3` feature is enabled, otherwise no
+/// hashing.
 impl Default for HashConfig {
     fn default() -> Self {
-        Self { enabled: true }
+        Self::new(cfg!(feature = "hash-blake3"))
+    }
+}
+
+impl std::fmt::Debug for HashConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("HashConfig");
+        debug
+            .field("enabled", &self.enabled)
+            .field("algorithm", &self.algorithm)
+            .field("stream_digest", &self.stream_digest)
+            .field("weak_checksum", &self.weak_checksum);
+        #[cfg(feature = "bao")]
+        debug.field("bao_outboard", &self.bao_outboard);
+        debug
+            .field("custom_hasher", &self.factory.is_some())
+            .field("keyed", &self.is_keyed())
+            .finish()
+    }
+}
+
+impl PartialEq for HashConfig {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "hash-blake3")]
+        if self.key != other.key {
+            return false;
+        }
+        #[cfg(feature = "bao")]
+        if self.bao_outboard != other.bao_outboard {
+            return false;
+        }
+        self.enabled == other.enabled
+            && self.algorithm == other.algorithm
+            && self.stream_digest == other.stream_digest
+            && self.weak_checksum == other.weak_checksum
+    }
+}
+
+impl Eq for HashConfig {}
+
+impl std::hash::Hash for HashConfig {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.enabled.hash(state);
+        self.algorithm.hash(state);
+        self.stream_digest.hash(state);
+        self.weak_checksum.hash(state);
+        #[cfg(feature = "bao")]
+        self.bao_outboard.hash(state);
+        #[cfg(feature = "hash-blake3")]
+        self.key.hash(state);
+    }
+}
+
+/// Algorithm prefix of the string form.
+const ALGORITHM_PREFIX: &str = "fastcdc";
+
+/// Binary size units of the string form, largest first.
+const SIZE_UNITS: [(&str, &str, usize); 3] = [
+    ("G", "GiB", 1 << 30),
+    ("M", "MiB", 1 << 20),
+    ("K", "KiB", 1 << 10),
+];
+
+impl fmt::Display for ChunkConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", ALGORITHM_PREFIX)?;
+        for (key, size) in [
+            ("min", self.min_size),
+            ("avg", self.avg_size),
+            ("max", self.max_size),
+        ] {
+            write!(f, "{}=", key)?;
+            match SIZE_UNITS
+                .iter()
+                .find(|&&(_, _, unit)| size >= unit && size % unit == 0)
+            {
+                Some(&(suffix, _, unit)) => write!(f, "{}{},", size / unit, suffix)?,
+                None => write!(f, "{},", size)?,
+            }
+        }
+        write!(f, "norm={}", self.normalization_level)
+    }
+}
+
+impl FromStr for ChunkConfig {
+    type Err = ChunkError;
+
+    fn from_str(s: &str) -> Result<Self, ChunkError> {
+        let params = match s.trim().split_once(':') {
+            Some((algorithm, params)) if algorithm.trim() == ALGORITHM_PREFIX => params,
+            Some(_) => {
+                return Err(parse_error(
+                    "algorithm",
+                    "unknown algorithm, expected `fastcdc`",
+                ));
+            }
+            None => return Err(parse_error("algorithm", "missing `fastcdc:` prefix")),
+        };
+
+        let mut sizes = [("min", None), ("avg", None), ("max", None)];
+        let mut norm = None;
+        for param in params.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let Some((key, value)) = param.split_once('=') else {
+                return Err(parse_error(param, "expected `key=value`"));
+            };
+            let (key, value) = (key.trim(), value.trim());
+            if key == "norm" {
+                if norm.is_some() {
+                    return Err(parse_error(key, "field given more than once"));
+                }
+                norm = match value.parse::<u8>() {
+                    Ok(level) if level <= 31 => Some(level),
+                    _ => {
+                        return Err(parse_error(
+                            key,
+                            "expected a normalization level from 0 to 31",
+                        ));
+                    }
+                };
+                continue;
+            }
+            let Some((_, slot)) = sizes.iter_mut().find(|(name, _)| *name == key) else {
+                return Err(parse_error(
+                    key,
+                    "unknown field, expected `min`, `avg`, `max` or `norm`",
+                ));
+            };
+            if slot.is_some() {
+                return Err(parse_error(key, "field given more than once"));
+            }
+            *slot = Some(parse_size(value).map_err(|message| parse_error(key, message))?);
+        }
+
+        let mut values = [0; 3];
+        for ((key, size), value) in sizes.into_iter().zip(&mut values) {
+            *value = match size {
+                Some(0) => return Err(parse_error(key, "chunk sizes must be non-zero")),
+                Some(size) => size,
+                None => return Err(parse_error(key, "missing field")),
+            };
+        }
+        let [min, avg, max] = values;
+        if min > avg {
+            return Err(parse_error("min", "min cannot be greater than avg"));
+        }
+        if avg > max {
+            return Err(parse_error("max", "max cannot be less than avg"));
+        }
+
+        let config = ChunkConfig::new(min, avg, max)?;
+        Ok(match norm {
+            Some(level) => config.with_normalization_level(level),
+            None => config,
+        })
+    }
+}
+
+/// Parses a size with an optional binary unit, such as `4096`, `16K` or `1MiB`.
+fn parse_size(value: &str) -> Result<usize, &'static str> {
+    let digits = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, suffix) = value.split_at(digits);
+    if number.is_empty() {
+        return Err("expected a size such as `16K`");
+    }
+    let unit = if suffix.is_empty() {
+        1
+    } else {
+        SIZE_UNITS
+            .iter()
+            .find(|&&(short, long, _)| suffix == short || suffix == long)
+            .map(|&(_, _, unit)| unit)
+            .ok_or("unknown size unit, expected K, M, G, KiB, MiB or GiB")?
+    };
+    number
+        .parse::<usize>()
+        .ok()
+        .and_then(|number| number.checked_mul(unit))
+        .ok_or("size is too large")
+}
+
+/// Builds a [`ChunkError::ParseConfig`] for `field`.
+fn parse_error(field: &str, message: &'static str) -> ChunkError {
+    ChunkError::ParseConfig {
+        field: field.to_string(),
+        message,
+    }
+}
+
+/// Serialized form of [`ChunkConfig`], without the keyed-CDC key.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "ChunkConfig", deny_unknown_fields)]
+struct ChunkConfigRepr {
+    min_size: usize,
+    avg_size: usize,
+    max_size: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    normalization_level: Option<u8>,
+    #[serde(default)]
+    hash: HashConfig,
+}
+
+#[cfg(feature = "serde")]
+impl From<ChunkConfig> for ChunkConfigRepr {
+    fn from(config: ChunkConfig) -> Self {
+        Self {
+            min_size: config.min_size,
+            avg_size: config.avg_size,
+            max_size: config.max_size,
+            normalization_level: Some(config.normalization_level),
+            hash: config.hash_config,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ChunkConfigRepr> for ChunkConfig {
+    type Error = ChunkError;
+
+    fn try_from(repr: ChunkConfigRepr) -> Result<Self, ChunkError> {
+        let mut config = ChunkConfig::new(repr.min_size, repr.avg_size, repr.max_size)?
+            .with_hash_config(repr.hash);
+        if let Some(level) = repr.normalization_level {
+            if level > 31 {
+                return Err(ChunkError::InvalidConfig {
+                    message: "normalization level must be at most 31",
+                });
+            }
+            config = config.with_normalization_level(level);
+        }
+        Ok(config)
+    }
+}
+
+/// Serialized form of [`HashConfig`], without keys or hasher factories.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "HashConfig", deny_unknown_fields)]
+struct HashConfigRepr {
+    enabled: bool,
+    #[serde(default)]
+    algorithm: HashAlgorithm,
+    #[serde(default)]
+    stream_digest: bool,
+    #[serde(default)]
+    weak_checksum: bool,
+    #[cfg(feature = "bao")]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    bao_outboard: bool,
+}
+
+#[cfg(feature = "serde")]
+impl From<HashConfig> for HashConfigRepr {
+    fn from(config: HashConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            algorithm: config.algorithm,
+            stream_digest: config.stream_digest,
+            weak_checksum: config.weak_checksum,
+            #[cfg(feature = "bao")]
+            bao_outboard: config.bao_outboard,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<HashConfigRepr> for HashConfig {
+    fn from(repr: HashConfigRepr) -> Self {
+        let mut config = HashConfig::new(repr.enabled)
+            .with_stream_digest(repr.stream_digest)
+            .with_weak_checksum(repr.weak_checksum);
+        #[cfg(feature = "bao")]
+        {
+            config.bao_outboard = repr.bao_outboard;
+        }
+        config.algorithm = repr.algorithm;
+        config
     }
 }
 
@@ -245,12 +996,20 @@ mod tests {
     }
 
     #[test]
-    fn test_chunk_config_invalid_non_power_of_two() {
-        assert!(ChunkConfig::new(5, 16, 64).is_err());
-        assert!(ChunkConfig::new(4, 17, 64).is_err());
-        assert!(ChunkConfig::new(4, 16, 65).is_err());
+    fn test_chunk_config_non_power_of_two() {
+        let config = ChunkConfig::new(48 * 1024, 192 * 1024, 768 * 1024).unwrap();
+        assert_eq!(config.avg_size(), 192 * 1024);
+        assert_eq!(config.normalization_level(), DEFAULT_NORMALIZATION_LEVEL);
+        assert!(config.validate().is_ok());
+
+        assert!(ChunkConfig::new(5, 17, 65).is_ok());
+        // Three-byte averages round to 2 mask bits, leaving no room to normalize.
+        assert_eq!(ChunkConfig::new(1, 3, 8).unwrap().normalization_level(), 0);
+        assert!(ChunkConfig::new(100, 99, 1000).is_err());
+        assert!(ChunkConfig::new(10, 100, 99).is_err());
     }
 
+    #[cfg(feature = "hash-blake3")]
     #[test]
     fn test_hash_config_default() {
         assert!(HashConfig::default().enabled);
@@ -272,6 +1031,195 @@ mod tests {
         assert!(!HashConfig::new(false).enabled);
     }
 
+    #[test]
+    fn test_hash_config_algorithm() {
+        assert_eq!(HashConfig::default().algorithm(), HashAlgorithm::Blake3);
+        assert!(HashConfig::disabled().build_hasher().is_none());
+
+        let custom = HashConfig::enabled().with_algorithm(HashAlgorithm::Custom(1));
+        assert!(
+            custom.build_hasher().is_none(),
+            "Custom algorithm without a hasher is unavailable"
+        );
+    }
+
+    #[test]
+    fn test_hash_config_with_hasher() {
+        #[derive(Debug)]
+        struct Fixed;
+
+        impl ChunkHasher for Fixed {
+            fn algorithm(&self) -> HashAlgorithm {
+                HashAlgorithm::Custom(42)
+            }
+            fn update(&mut self, _data: &[u8]) {}
+            fn finalize(&self) -> crate::ChunkHash {
+                crate::ChunkHash::with_algorithm(HashAlgorithm::Custom(42), [7; 32])
+            }
+            fn reset(&mut self) {}
+            fn fork(&self) -> Box<dyn ChunkHasher> {
+                Box::new(Fixed)
+            }
+        }
+
+        let config =
+            HashConfig::enabled().with_hasher(HashAlgorithm::Custom(42), || Box::new(Fixed));
+        assert_eq!(config.algorithm(), HashAlgorithm::Custom(42));
+        assert_ne!(config, HashConfig::enabled());
+        assert_ne!(
+            config,
+            HashConfig::enabled().with_hasher(HashAlgorithm::Custom(43), || Box::new(Fixed))
+        );
+        assert!(matches!(
+            HashConfig::enabled()
+                .with_hasher(HashAlgorithm::Custom(43), || Box::new(Fixed))
+                .validate(),
+            Err(ChunkError::InvalidConfig { .. })
+        ));
+
+        let hasher = config.build_hasher().unwrap();
+        assert_eq!(hasher.finalize().as_bytes(), &[7; 32]);
+
+        let reverted = config.with_algorithm(HashAlgorithm::Blake3);
+        assert_eq!(reverted, HashConfig::enabled());
+    }
+
+    #[test]
+    #[should_panic(expected = "produced a")]
+    fn test_hash_config_checks_custom_hash_tags() {
+        #[derive(Debug)]
+        struct Mislabeled;
+
+        impl ChunkHasher for Mislabeled {
+            fn algorithm(&self) -> HashAlgorithm {
+                HashAlgorithm::Custom(42)
+            }
+            fn update(&mut self, _data: &[u8]) {}
+            fn finalize(&self) -> crate::ChunkHash {
+                crate::ChunkHash::with_algorithm(HashAlgorithm::Blake3, [7; 32])
+            }
+            fn reset(&mut self) {}
+            fn fork(&self) -> Box<dyn ChunkHasher> {
+                Box::new(Mislabeled)
+            }
+        }
+
+        let config =
+            HashConfig::enabled().with_hasher(HashAlgorithm::Custom(42), || Box::new(Mislabeled));
+        config.build_hasher().unwrap().digest(b"data");
+    }
+
+    #[test]
+    fn test_hash_config_validate() {
+        assert!(HashConfig::disabled().validate().is_ok());
+        assert!(HashConfig::default().validate().is_ok());
+        assert!(
+            HashConfig::disabled()
+                .with_algorithm(HashAlgorithm::Custom(1))
+                .validate()
+                .is_ok(),
+            "a disabled configuration never builds a hasher"
+        );
+
+        for invalid in [
+            HashConfig::enabled().with_algorithm(HashAlgorithm::Custom(1)),
+            HashConfig::enabled().with_algorithm(HashAlgorithm::Blake3Keyed),
+        ] {
+            assert!(matches!(
+                invalid.validate(),
+                Err(ChunkError::InvalidConfig { .. })
+            ));
+            assert!(
+                ChunkConfig::default()
+                    .with_hash_config(invalid)
+                    .validate()
+                    .is_err()
+            );
+        }
+
+        #[cfg(not(feature = "hash-sha256"))]
+        assert!(
+            HashConfig::enabled()
+                .with_algorithm(HashAlgorithm::Sha256)
+                .validate()
+                .is_err()
+        );
+        #[cfg(not(feature = "hash-blake3"))]
+        assert!(HashConfig::enabled().validate().is_err());
+    }
+
+    #[cfg(feature = "hash-blake3")]
+    #[test]
+    fn test_hash_config_with_key() {
+        let key = [0x11; 32];
+        let config = HashConfig::enabled().with_key(key);
+        assert!(config.is_keyed());
+        assert_eq!(config.algorithm(), HashAlgorithm::Blake3Keyed);
+        assert_ne!(config, HashConfig::enabled().with_key([0x22; 32]));
+
+        let hash = config.build_hasher().unwrap().digest(b"data");
+        assert_eq!(hash.algorithm(), HashAlgorithm::Blake3Keyed);
+        assert_eq!(
+            hash.as_bytes(),
+            blake3::keyed_hash(&key, b"data").as_bytes()
+        );
+
+        let unkeyed = config.with_algorithm(HashAlgorithm::Blake3);
+        assert!(!unkeyed.is_keyed());
+        assert!(
+            HashConfig::enabled()
+                .with_algorithm(HashAlgorithm::Blake3Keyed)
+                .build_hasher()
+                .is_none(),
+            "Keyed algorithm without a key is unavailable"
+        );
+    }
+
+    #[cfg(feature = "hash-blake3")]
+    #[test]
+    fn test_hash_config_with_derived_key() {
+        let master = b"master secret";
+        let tenant_a = HashConfig::enabled().with_derived_key("chunkrs test tenant-a", master);
+        let tenant_b = HashConfig::enabled().with_derived_key("chunkrs test tenant-b", master);
+
+        let expected = blake3::derive_key("chunkrs test tenant-a", master);
+        assert_eq!(tenant_a, HashConfig::enabled().with_key(expected));
+
+        let hash_a = tenant_a.build_hasher().unwrap().digest(b"same data");
+        let hash_b = tenant_b.build_hasher().unwrap().digest(b"same data");
+        assert_ne!(
+            hash_a, hash_b,
+            "Tenants must get unrelated chunk identities"
+        );
+    }
+
+    #[cfg(feature = "hash-blake3")]
+    #[test]
+    fn test_hash_config_debug_redacts_key() {
+        let config = HashConfig::enabled().with_key([0xAB; 32]);
+        let debug = format!("{:?}", config);
+        assert!(debug.contains("keyed: true"));
+        assert!(!debug.contains("171"), "Key bytes must not be printed");
+    }
+
+    #[test]
+    fn test_hash_config_stream_digest() {
+        assert!(!HashConfig::default().stream_digest());
+
+        let config = HashConfig::enabled().with_stream_digest(true);
+        assert!(config.stream_digest());
+        assert_ne!(config, HashConfig::enabled());
+    }
+
+    #[test]
+    fn test_hash_config_weak_checksum() {
+        assert!(!HashConfig::default().weak_checksum());
+
+        let config = HashConfig::disabled().with_weak_checksum(true);
+        assert!(config.weak_checksum());
+        assert_ne!(config, HashConfig::disabled());
+    }
+
     #[test]
     fn test_chunk_config_with_hash_config() {
         let hash_cfg = HashConfig::disabled();
@@ -284,4 +1232,242 @@ mod tests {
         let config = ChunkConfig::default().with_min_size(0);
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_chunk_config_display() {
+        assert_eq!(
+            ChunkConfig::default().to_string(),
+            "fastcdc:min=4K,avg=16K,max=64K,norm=2"
+        );
+        let config = ChunkConfig::new(1000, 1536, 3 << 30)
+            .unwrap()
+            .with_normalization_level(0);
+        assert_eq!(
+            config.to_string(),
+            "fastcdc:min=1000,avg=1536,max=3G,norm=0"
+        );
+        assert_eq!(config.to_string().parse::<ChunkConfig>().unwrap(), config);
+    }
+
+    #[test]
+    fn test_chunk_config_from_str() {
+        let default: ChunkConfig = "fastcdc:min=4K,avg=16K,max=64K,norm=2".parse().unwrap();
+        assert_eq!(default, ChunkConfig::default());
+
+        let units: ChunkConfig = " fastcdc: min=4096, avg=16KiB, max=1MiB, ".parse().unwrap();
+        assert_eq!(
+            units,
+            ChunkConfig::new(4096, 16 * 1024, 1024 * 1024).unwrap()
+        );
+
+        let large: ChunkConfig = "fastcdc:min=1M,avg=4M,max=1G,norm=1".parse().unwrap();
+        assert_eq!(
+            (
+                large.min_size(),
+                large.max_size(),
+                large.normalization_level()
+            ),
+            (1 << 20, 1 << 30, 1)
+        );
+
+        // A missing `norm` takes the default `new` would choose.
+        let tiny: ChunkConfig = "fastcdc:min=2,avg=4,max=8".parse().unwrap();
+        assert_eq!(tiny, ChunkConfig::new(2, 4, 8).unwrap());
+    }
+
+    #[test]
+    fn test_chunk_config_from_str_errors() {
+        let cases = [
+            ("min=4K,avg=16K,max=64K", "algorithm"),
+            ("rabin:min=4K,avg=16K,max=64K", "algorithm"),
+            ("fastcdc:min=4K,avg=16Q,max=64K", "avg"),
+            ("fastcdc:min=4K,avg=K,max=64K", "avg"),
+            ("fastcdc:min=4K,avg=16K,max=99999999999999999999", "max"),
+            ("fastcdc:min=4K,avg=16K", "max"),
+            ("fastcdc:min=4K,min=8K,avg=16K,max=64K", "min"),
+            ("fastcdc:min=4K,avg=16K,max=64K,mean=8K", "mean"),
+            ("fastcdc:min=4K,avg=16K,max=64K,norm", "norm"),
+            ("fastcdc:min=4K,avg=16K,max=64K,norm=32", "norm"),
+            ("fastcdc:min=0,avg=16K,max=64K", "min"),
+            ("fastcdc:min=32K,avg=16K,max=64K", "min"),
+            ("fastcdc:min=4K,avg=16K,max=8K", "max"),
+        ];
+        for (input, expected) in cases {
+            match input.parse::<ChunkConfig>() {
+                Err(ChunkError::ParseConfig { field, .. }) => {
+                    assert_eq!(field, expected, "{}", input)
+                }
+                other => panic!("{}: unexpected {:?}", input, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_pinned() {
+        // Changing any of these values breaks every stored fingerprint; do it
+        // only together with a version bump that really changes boundaries.
+        // Hashing is spelled out: without `hash-blake3` it is off by default.
+        let default = ChunkConfig::default().with_hash_config(HashConfig::enabled());
+        assert_eq!(default.fingerprint(), 0x2d67_6e09_402b_608e);
+        assert_eq!(
+            ChunkConfig::new(48 * 1024, 192 * 1024, 768 * 1024)
+                .unwrap()
+                .with_hash_config(HashConfig::enabled())
+                .fingerprint(),
+            0x53cf_0c9f_1f2b_ed44
+        );
+        assert_eq!(
+            default.with_normalization_level(0).fingerprint(),
+            0x90d0_ca66_2dcb_2f30
+        );
+        assert_eq!(
+            default
+                .with_hash_config(HashConfig::disabled())
+                .fingerprint(),
+            0xd514_4d64_e99b_daab
+        );
+        assert_eq!(
+            default
+                .with_hash_config(HashConfig::enabled().with_algorithm(HashAlgorithm::Sha256))
+                .fingerprint(),
+            0x05a9_e1df_1869_c522
+        );
+    }
+
+    #[cfg(feature = "keyed-cdc")]
+    #[test]
+    fn test_fingerprint_pinned_keyed() {
+        let keyed = ChunkConfig::default().with_keyed_gear_table(Some([0xC3; 32]));
+        assert_eq!(keyed.fingerprint(), 0xbd8c_1534_1c0a_891d);
+        assert_eq!(
+            keyed
+                .with_hash_config(HashConfig::enabled().with_key([0x5A; 32]))
+                .fingerprint(),
+            0x1a74_86c0_4e54_ad00
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_tracks_config() {
+        let base = ChunkConfig::new(16, 64, 256)
+            .unwrap()
+            .with_hash_config(HashConfig::enabled());
+        let variants = [
+            ChunkConfig::new(32, 64, 256).unwrap(),
+            ChunkConfig::new(16, 128, 256).unwrap(),
+            ChunkConfig::new(16, 64, 512).unwrap(),
+            base.with_normalization_level(1),
+            base.with_hash_config(HashConfig::disabled()),
+            base.with_hash_config(HashConfig::enabled().with_algorithm(HashAlgorithm::Xxh3)),
+        ];
+        for variant in variants {
+            assert_ne!(base.fingerprint(), variant.fingerprint(), "{:?}", variant);
+        }
+
+        // Settings that don't change chunks or their hashes are not covered.
+        let extras = HashConfig::enabled()
+            .with_stream_digest(true)
+            .with_weak_checksum(true);
+        assert_eq!(
+            base.fingerprint(),
+            base.with_hash_config(extras).fingerprint()
+        );
+        assert_eq!(
+            base.with_hash_config(HashConfig::disabled()).fingerprint(),
+            base.with_hash_config(HashConfig::disabled().with_algorithm(HashAlgorithm::Sha256))
+                .fingerprint()
+        );
+    }
+
+    #[cfg(feature = "keyed-cdc")]
+    #[test]
+    fn test_fingerprint_tracks_keys() {
+        let base = ChunkConfig::new(16, 64, 256).unwrap();
+        let gear_a = base.with_keyed_gear_table(Some([1; 32]));
+        let gear_b = base.with_keyed_gear_table(Some([2; 32]));
+        let hash_a = base.with_hash_config(HashConfig::enabled().with_key([1; 32]));
+        let fingerprints = [base, gear_a, gear_b, hash_a].map(|config| config.fingerprint());
+        for (i, a) in fingerprints.iter().enumerate() {
+            for b in &fingerprints[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        assert_eq!(
+            gear_a.fingerprint(),
+            base.with_keyed_gear_table(Some([1; 32])).fingerprint()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_chunk_config_serde_round_trip() {
+        let config = ChunkConfig::new(48 * 1024, 192 * 1024, 768 * 1024)
+            .unwrap()
+            .with_normalization_level(1)
+            .with_hash_config(HashConfig::enabled().with_weak_checksum(true));
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            json,
+            r#"{"min_size":49152,"avg_size":196608,"max_size":786432,"normalization_level":1,"hash":{"enabled":true,"algorithm":"blake3","stream_digest":false,"weak_checksum":true}}"#
+        );
+        assert_eq!(serde_json::from_str::<ChunkConfig>(&json).unwrap(), config);
+
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&config, &mut cbor).unwrap();
+        assert_eq!(
+            ciborium::from_reader::<ChunkConfig, _>(&cbor[..]).unwrap(),
+            config
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_chunk_config_serde_validates() {
+        let minimal: ChunkConfig =
+            serde_json::from_str(r#"{"min_size":4096,"avg_size":16384,"max_size":65536}"#).unwrap();
+        assert_eq!(minimal, ChunkConfig::default());
+
+        for invalid in [
+            r#"{"min_size":0,"avg_size":16384,"max_size":65536}"#,
+            r#"{"min_size":32768,"avg_size":16384,"max_size":65536}"#,
+            r#"{"min_size":4096,"avg_size":16384,"max_size":65536,"normalization_level":32}"#,
+            r#"{"min_size":4096,"avg_size":16384,"max_size":65536,"avg":1}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<ChunkConfig>(invalid).is_err(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[cfg(all(feature = "serde", feature = "hash-blake3"))]
+    #[test]
+    fn test_hash_config_serde_omits_key() {
+        let config = HashConfig::enabled().with_key([0x5A; 32]);
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            json,
+            r#"{"enabled":true,"algorithm":"blake3-keyed","stream_digest":false,"weak_checksum":false}"#
+        );
+
+        let restored: HashConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.algorithm(), HashAlgorithm::Blake3Keyed);
+        assert!(!restored.is_keyed());
+        assert!(restored.validate().is_err(), "the key must be set again");
+        assert_eq!(restored.with_key([0x5A; 32]), config);
+    }
+
+    #[cfg(all(feature = "serde", feature = "keyed-cdc"))]
+    #[test]
+    fn test_chunk_config_serde_omits_gear_key() {
+        let config = ChunkConfig::default().with_keyed_gear_table(Some([0xC3; 32]));
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(!json.contains("195"), "key bytes leaked: {}", json);
+
+        let restored: ChunkConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.keyed_gear_table_key(), None);
+        assert_eq!(restored.with_keyed_gear_table(Some([0xC3; 32])), config);
+    }
 }
@@ -5,6 +5,7 @@
 //! - [`ChunkError`] - Represents all possible errors during chunking
 
 use std::fmt;
+use std::io;
 
 /// Errors that can occur during chunking operations.
 ///
@@ -14,6 +15,9 @@ use std::fmt;
 /// # Variants
 ///
 /// - [`ChunkError::InvalidConfig`] - Invalid configuration parameter
+/// - [`ChunkError::ParseConfig`] - Unparsable configuration string
+/// - [`ChunkError::InvalidInput`] - Input that an operation cannot accept
+/// - [`ChunkError::Io`] - I/O error from an underlying reader or writer
 ///
 /// # Example
 ///
@@ -23,6 +27,11 @@ use std::fmt;
 /// fn handle_error(err: ChunkError) {
 ///     match err {
 ///         ChunkError::InvalidConfig { message } => eprintln!("Config error: {}", message),
+///         ChunkError::ParseConfig { field, message } => {
+///             eprintln!("Config error in `{}`: {}", field, message)
+///         }
+///         ChunkError::InvalidInput { message } => eprintln!("Input error: {}", message),
+///         ChunkError::Io { source } => eprintln!("I/O error: {}", source),
 ///     }
 /// }
 /// ```
@@ -33,11 +42,41 @@ pub enum ChunkError {
     /// This error is raised when the chunking configuration is invalid, such as:
     /// - Zero or negative chunk sizes
     /// - Minimum size greater than average or average greater than maximum
-    /// - Non-power-of-2 sizes (FastCDC requirement)
     InvalidConfig {
         /// Description of what was invalid.
         message: &'static str,
     },
+
+    /// Configuration string that could not be parsed.
+    ///
+    /// Raised by [`ChunkConfig`](crate::ChunkConfig)'s `FromStr`
+    /// implementation, naming the part of the string at fault:
+    /// - `"algorithm"` for a missing or unknown algorithm prefix
+    /// - A key such as `"avg"` for a bad, missing, duplicate or unknown field
+    ParseConfig {
+        /// The part of the string that was invalid.
+        field: String,
+        /// Description of what was invalid.
+        message: &'static str,
+    },
+
+    /// Input that an operation cannot accept.
+    ///
+    /// This error is raised when data passed to a helper is inconsistent, such as:
+    /// - A chunk without a hash where one is required
+    /// - A chunk whose offset does not continue the stream
+    InvalidInput {
+        /// Description of what was invalid.
+        message: &'static str,
+    },
+
+    /// I/O error from an underlying reader or writer.
+    ///
+    /// Raised by the I/O adapters such as [`ChunkReader`](crate::ChunkReader).
+    Io {
+        /// The underlying error.
+        source: io::Error,
+    },
 }
 
 impl fmt::Display for ChunkError {
@@ -46,13 +85,31 @@ impl fmt::Display for ChunkError {
             ChunkError::InvalidConfig { message } => {
                 write!(f, "invalid config: {}", message)
             }
+            ChunkError::ParseConfig { field, message } => {
+                write!(f, "invalid config string: {}: {}", field, message)
+            }
+            ChunkError::InvalidInput { message } => {
+                write!(f, "invalid input: {}", message)
+            }
+            ChunkError::Io { source } => {
+                write!(f, "I/O error: {}", source)
+            }
         }
     }
 }
 
 impl std::error::Error for ChunkError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+        match self {
+            ChunkError::Io { source } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ChunkError {
+    fn from(source: io::Error) -> Self {
+        ChunkError::Io { source }
     }
 }
 
@@ -72,6 +129,26 @@ mod tests {
         assert!(s.contains("test error message"));
     }
 
+    #[test]
+    fn test_error_display_invalid_input() {
+        let err = ChunkError::InvalidInput {
+            message: "chunk has no hash",
+        };
+        assert_eq!(err.to_string(), "invalid input: chunk has no hash");
+    }
+
+    #[test]
+    fn test_error_display_parse_config() {
+        let err = ChunkError::ParseConfig {
+            field: "avg".into(),
+            message: "unknown size unit",
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid config string: avg: unknown size unit"
+        );
+    }
+
     #[test]
     fn test_error_source() {
         let err = ChunkError::InvalidConfig { message: "test" };
@@ -80,6 +157,13 @@ mod tests {
         assert!(err.source().is_none());
     }
 
+    #[test]
+    fn test_error_io() {
+        let err = ChunkError::from(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated"));
+        assert_eq!(err.to_string(), "I/O error: truncated");
+        assert!(err.source().is_some());
+    }
+
     #[test]
     fn test_error_debug() {
         let err = ChunkError::InvalidConfig {
@@ -0,0 +1,215 @@
+//! Hash algorithm identifiers.
+
+use std::fmt;
+
+/// Identifies the algorithm that produced a [`ChunkHash`](crate::ChunkHash).
+///
+/// Every `ChunkHash` carries its algorithm so hashes from different
+/// algorithms never compare equal, even if their digest bytes happen to match.
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::HashAlgorithm;
+///
+/// assert_eq!(HashAlgorithm::Blake3.name(), "blake3");
+/// assert_eq!(HashAlgorithm::Blake3.digest_len(), 32);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum HashAlgorithm {
+    /// BLAKE3 (32-byte digest, requires `hash-blake3` for computation).
+    #[default]
+    Blake3,
+    /// Keyed BLAKE3 (32-byte digest, requires `hash-blake3` for computation).
+    ///
+    /// Only holders of the key can compute or confirm these hashes.
+    Blake3Keyed,
+    /// SHA-256 (32-byte digest, requires `hash-sha256` for computation).
+    Sha256,
+    /// XXH3-128 (16-byte digest, requires `hash-xxh3` for computation).
+    ///
+    /// **Not cryptographic**: only use for trusted data.
+    Xxh3,
+    /// Application-defined algorithm supplied through a custom
+    /// [`ChunkHasher`](crate::ChunkHasher).
+    ///
+    /// The identifier is chosen by the application; using the algorithm's
+    /// multicodec code keeps it unambiguous. Digests are 32 bytes.
+    Custom(u64),
+}
+
+impl HashAlgorithm {
+    /// Returns the short lowercase name of the algorithm.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Blake3 => "blake3",
+            HashAlgorithm::Blake3Keyed => "blake3-keyed",
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Xxh3 => "xxh3-128",
+            HashAlgorithm::Custom(_) => "custom",
+        }
+    }
+
+    /// Returns the digest length in bytes.
+    pub const fn digest_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Blake3 => 32,
+            HashAlgorithm::Blake3Keyed => 32,
+            HashAlgorithm::Sha256 => 32,
+            HashAlgorithm::Xxh3 => 16,
+            HashAlgorithm::Custom(_) => 32,
+        }
+    }
+
+    /// Returns the multicodec code used in multihash encodings.
+    ///
+    /// Keyed BLAKE3 has no registered code and uses the private-use code
+    /// `0x30001e`. Custom algorithms return their own identifier.
+    pub const fn multihash_code(&self) -> u64 {
+        match self {
+            HashAlgorithm::Blake3 => 0x1e,
+            HashAlgorithm::Blake3Keyed => 0x30_001e,
+            HashAlgorithm::Sha256 => 0x12,
+            HashAlgorithm::Xxh3 => 0xb3e4,
+            HashAlgorithm::Custom(code) => *code,
+        }
+    }
+
+    /// Returns the algorithm for a multicodec code.
+    ///
+    /// Unknown codes map to [`HashAlgorithm::Custom`]; a custom algorithm that
+    /// reuses a built-in code decodes as that built-in algorithm.
+    pub const fn from_multihash_code(code: u64) -> Self {
+        match code {
+            0x1e => HashAlgorithm::Blake3,
+            0x30_001e => HashAlgorithm::Blake3Keyed,
+            0x12 => HashAlgorithm::Sha256,
+            0xb3e4 => HashAlgorithm::Xxh3,
+            other => HashAlgorithm::Custom(other),
+        }
+    }
+
+    /// Returns `true` if the algorithm is collision-resistant.
+    ///
+    /// Only cryptographic hashes are safe as content identities for untrusted
+    /// data. Custom algorithms are conservatively reported as non-cryptographic.
+    pub const fn is_cryptographic(&self) -> bool {
+        match self {
+            HashAlgorithm::Blake3 | HashAlgorithm::Blake3Keyed | HashAlgorithm::Sha256 => true,
+            HashAlgorithm::Xxh3 | HashAlgorithm::Custom(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashAlgorithm::Custom(code) => write!(f, "custom-{:#x}", code),
+            other => write!(f, "{}", other.name()),
+        }
+    }
+}
+
+/// Serialized as its [`Display`](fmt::Display) name, e.g. `"blake3"` or
+/// `"custom-0x1234"` (requires `serde` feature).
+#[cfg(feature = "serde")]
+impl serde::Serialize for HashAlgorithm {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HashAlgorithm {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, Unexpected};
+
+        let name = String::deserialize(deserializer)?;
+        let algorithm = match name.as_ref() {
+            "blake3" => Some(HashAlgorithm::Blake3),
+            "blake3-keyed" => Some(HashAlgorithm::Blake3Keyed),
+            "sha256" => Some(HashAlgorithm::Sha256),
+            "xxh3-128" => Some(HashAlgorithm::Xxh3),
+            other => other
+                .strip_prefix("custom-0x")
+                .and_then(|code| u64::from_str_radix(code, 16).ok())
+                .map(HashAlgorithm::Custom),
+        };
+        algorithm.ok_or_else(|| {
+            D::Error::invalid_value(Unexpected::Str(&name), &"a hash algorithm name")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_default() {
+        assert_eq!(HashAlgorithm::default(), HashAlgorithm::Blake3);
+    }
+
+    #[test]
+    fn test_algorithm_display() {
+        assert_eq!(HashAlgorithm::Blake3.to_string(), "blake3");
+        assert_eq!(HashAlgorithm::Sha256.to_string(), "sha256");
+        assert_eq!(HashAlgorithm::Xxh3.to_string(), "xxh3-128");
+        assert_eq!(HashAlgorithm::Custom(0x12).to_string(), "custom-0x12");
+    }
+
+    #[test]
+    fn test_algorithm_digest_len() {
+        assert_eq!(HashAlgorithm::Blake3.digest_len(), 32);
+        assert_eq!(HashAlgorithm::Xxh3.digest_len(), 16);
+        assert_eq!(HashAlgorithm::Custom(1).digest_len(), 32);
+    }
+
+    #[test]
+    fn test_algorithm_multihash_code_round_trip() {
+        for algorithm in [
+            HashAlgorithm::Blake3,
+            HashAlgorithm::Blake3Keyed,
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Xxh3,
+            HashAlgorithm::Custom(0x1234),
+        ] {
+            assert_eq!(
+                HashAlgorithm::from_multihash_code(algorithm.multihash_code()),
+                algorithm
+            );
+        }
+        assert_eq!(HashAlgorithm::Sha256.multihash_code(), 0x12);
+        assert_eq!(HashAlgorithm::Blake3.multihash_code(), 0x1e);
+    }
+
+    #[test]
+    fn test_algorithm_is_cryptographic() {
+        assert!(HashAlgorithm::Blake3.is_cryptographic());
+        assert!(HashAlgorithm::Blake3Keyed.is_cryptographic());
+        assert!(HashAlgorithm::Sha256.is_cryptographic());
+        assert!(!HashAlgorithm::Xxh3.is_cryptographic());
+        assert!(!HashAlgorithm::Custom(1).is_cryptographic());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_algorithm_serde() {
+        for algorithm in [
+            HashAlgorithm::Blake3,
+            HashAlgorithm::Blake3Keyed,
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Xxh3,
+            HashAlgorithm::Custom(0x1234),
+        ] {
+            let json = serde_json::to_string(&algorithm).unwrap();
+            assert_eq!(json, format!("\"{}\"", algorithm));
+            assert_eq!(
+                serde_json::from_str::<HashAlgorithm>(&json).unwrap(),
+                algorithm
+            );
+        }
+        assert!(serde_json::from_str::<HashAlgorithm>("\"md5\"").is_err());
+    }
+}
@@ -10,7 +10,7 @@
 //! - **Deterministic**: Same input always produces the same hash
 //! - **Incremental**: Supports streaming updates for large data
 
-#[cfg(feature = "hash-blake3")]
+use super::{ChunkHasher, HashAlgorithm};
 use crate::chunk::ChunkHash;
 
 /// A hasher that computes BLAKE3 hashes.
@@ -37,6 +37,7 @@ use crate::chunk::ChunkHash;
 #[derive(Debug, Clone)]
 pub struct Blake3Hasher {
     state: blake3::Hasher,
+    algorithm: HashAlgorithm,
 }
 
 impl Blake3Hasher {
@@ -54,6 +55,7 @@ impl Blake3Hasher {
     pub fn new() -> Self {
         Self {
             state: blake3::Hasher::new(),
+            algorithm: HashAlgorithm::Blake3,
         }
     }
 
@@ -61,14 +63,15 @@ impl Blake3Hasher {
     ///
     /// Keyed hashing uses a 32-byte key to ensure that only those with the key
     /// can verify or forge hashes. This is useful for HMAC-like applications.
+    /// Hashes are tagged [`HashAlgorithm::Blake3Keyed`].
     ///
     /// # Arguments
     ///
     /// * `key` - A 32-byte key for the keyed hash
-    #[allow(dead_code)]
     pub fn new_keyed(key: &[u8; 32]) -> Self {
         Self {
             state: blake3::Hasher::new_keyed(key),
+            algorithm: HashAlgorithm::Blake3Keyed,
         }
     }
 
@@ -90,7 +93,6 @@ impl Blake3Hasher {
     /// hasher.update(b"hello ");
     /// hasher.update(b"world");
     /// ```
-    #[allow(dead_code)]
     pub fn update(&mut self, data: &[u8]) {
         self.state.update(data);
     }
@@ -113,9 +115,8 @@ impl Blake3Hasher {
     /// hasher.update(b"hello world");
     /// let hash = hasher.finalize();
     /// ```
-    #[allow(dead_code)]
     pub fn finalize(&self) -> ChunkHash {
-        ChunkHash::new(self.state.finalize().into())
+        ChunkHash::with_algorithm(self.algorithm, self.state.finalize().into())
     }
 
     /// Resets the hasher to its initial state.
@@ -138,7 +139,6 @@ impl Blake3Hasher {
     ///
     /// assert_ne!(hash1, hash2);
     /// ```
-    #[allow(dead_code)]
     pub fn reset(&mut self) {
         self.state.reset();
     }
@@ -164,7 +164,7 @@ impl Blake3Hasher {
     /// let hash = Blake3Hasher::hash(b"hello world");
     /// ```
     pub fn hash(data: &[u8]) -> ChunkHash {
-        ChunkHash::new(blake3::hash(data).into())
+        ChunkHash::with_algorithm(HashAlgorithm::Blake3, blake3::hash(data).into())
     }
 }
 
@@ -174,6 +174,37 @@ impl Default for Blake3Hasher {
     }
 }
 
+impl ChunkHasher for Blake3Hasher {
+    fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        Blake3Hasher::update(self, data);
+    }
+
+    fn finalize(&self) -> ChunkHash {
+        Blake3Hasher::finalize(self)
+    }
+
+    fn reset(&mut self) {
+        Blake3Hasher::reset(self);
+    }
+
+    fn fork(&self) -> Box<dyn ChunkHasher> {
+        Box::new(self.clone())
+    }
+
+    fn digest(&mut self, data: &[u8]) -> ChunkHash {
+        if self.algorithm == HashAlgorithm::Blake3 {
+            return Blake3Hasher::hash(data);
+        }
+        self.reset();
+        self.update(data);
+        Blake3Hasher::finalize(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,6 +256,37 @@ mod tests {
         assert_eq!(hash2, expected, "Reset must clear previous state");
     }
 
+    #[test]
+    fn test_chunk_hasher_trait() {
+        let mut hasher: Box<dyn ChunkHasher> = Box::new(Blake3Hasher::new());
+        hasher.update(b"stale");
+
+        let hash = hasher.digest(b"hello world");
+        assert_eq!(hash, Blake3Hasher::hash(b"hello world"));
+        assert_eq!(hash.algorithm(), HashAlgorithm::Blake3);
+    }
+
+    #[test]
+    fn test_keyed_hashing() {
+        let key = [0x42u8; 32];
+        let mut hasher = Blake3Hasher::new_keyed(&key);
+        hasher.update(b"hello world");
+        let keyed = hasher.finalize();
+
+        assert_eq!(keyed.algorithm(), HashAlgorithm::Blake3Keyed);
+        assert_eq!(
+            keyed.as_bytes(),
+            blake3::keyed_hash(&key, b"hello world").as_bytes()
+        );
+        assert_ne!(
+            keyed.as_bytes(),
+            Blake3Hasher::hash(b"hello world").as_bytes()
+        );
+
+        hasher.update(b"stale");
+        assert_eq!(ChunkHasher::digest(&mut hasher, b"hello world"), keyed);
+    }
+
     #[test]
     fn test_hasher_multiple_updates() {
         let mut hasher = Blake3Hasher::new();
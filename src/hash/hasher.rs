@@ -0,0 +1,93 @@
+//! The pluggable chunk hasher trait.
+
+use std::fmt;
+
+use super::HashAlgorithm;
+use crate::chunk::ChunkHash;
+
+/// Factory that creates a fresh [`ChunkHasher`].
+///
+/// Used by [`HashConfig::with_hasher`](crate::HashConfig::with_hasher) to plug
+/// an application-supplied algorithm into the [`Chunker`](crate::Chunker).
+/// A plain function keeps [`HashConfig`](crate::HashConfig) `Copy`; the
+/// [`HashAlgorithm`] passed alongside it identifies the hasher.
+pub type HasherFactory = fn() -> Box<dyn ChunkHasher>;
+
+/// An incremental hash function used to compute chunk identities.
+///
+/// The [`Chunker`](crate::Chunker) creates one hasher per stream and calls
+/// [`reset`](ChunkHasher::reset) before hashing each chunk, so implementations
+/// only need to support the `update`/`finalize`/`reset` cycle, plus
+/// [`fork`](ChunkHasher::fork) to copy their state.
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::{ChunkConfig, ChunkHash, ChunkHasher, Chunker, HashAlgorithm, HashConfig};
+/// use bytes::Bytes;
+///
+/// /// A toy checksum: byte sum spread across the digest.
+/// #[derive(Debug, Clone, Default)]
+/// struct SumHasher(u64);
+///
+/// impl ChunkHasher for SumHasher {
+///     fn algorithm(&self) -> HashAlgorithm {
+///         HashAlgorithm::Custom(0x5355_4d)
+///     }
+///
+///     fn update(&mut self, data: &[u8]) {
+///         self.0 = data.iter().fold(self.0, |acc, &b| acc.wrapping_add(b as u64));
+///     }
+///
+///     fn finalize(&self) -> ChunkHash {
+///         let mut bytes = [0u8; 32];
+///         bytes[..8].copy_from_slice(&self.0.to_le_bytes());
+///         ChunkHash::with_algorithm(self.algorithm(), bytes)
+///     }
+///
+///     fn reset(&mut self) {
+///         self.0 = 0;
+///     }
+///
+///     fn fork(&self) -> Box<dyn ChunkHasher> {
+///         Box::new(self.clone())
+///     }
+/// }
+///
+/// let hash_config = HashConfig::enabled()
+///     .with_hasher(HashAlgorithm::Custom(0x5355_4d), || Box::new(SumHasher::default()));
+/// let mut chunker = Chunker::new(ChunkConfig::default().with_hash_config(hash_config));
+/// chunker.push_buf(Bytes::from(&b"abc"[..]));
+///
+/// let chunk = chunker.finish().unwrap();
+/// let hash = chunk.hash.unwrap();
+/// assert_eq!(hash.algorithm(), HashAlgorithm::Custom(0x5355_4d));
+/// assert_eq!(hash.as_bytes()[..8], (97u64 + 98 + 99).to_le_bytes());
+/// ```
+pub trait ChunkHasher: fmt::Debug + Send + Sync {
+    /// Returns the algorithm this hasher implements.
+    fn algorithm(&self) -> HashAlgorithm;
+
+    /// Feeds more data into the hash state.
+    fn update(&mut self, data: &[u8]);
+
+    /// Returns the hash of all data fed since the last reset.
+    fn finalize(&self) -> ChunkHash;
+
+    /// Resets the hasher to its initial state.
+    fn reset(&mut self);
+
+    /// Returns a copy of this hasher including its current state.
+    ///
+    /// Used when cloning a [`Chunker`](crate::Chunker), which may hold a
+    /// partially hashed chunk or stream. For a `Clone` hasher this is
+    /// `Box::new(self.clone())`.
+    fn fork(&self) -> Box<dyn ChunkHasher>;
+
+    /// Hashes `data` in one shot, discarding any previous state.
+    fn digest(&mut self, data: &[u8]) -> ChunkHash {
+        self.reset();
+        self.update(data);
+        self.finalize()
+    }
+}
@@ -1,14 +1,32 @@
 //! Strong hash implementations for chunk identity.
 //!
-//! This module provides cryptographic hashing functionality for computing
-//! content hashes of chunks. Currently supports BLAKE3 via the `hash-blake3`
-//! feature.
+//! This module provides the pluggable hashing interface and the built-in
+//! algorithms used to compute content hashes of chunks.
 //!
+//! - [`HashAlgorithm`] - Identifies which algorithm produced a hash
+//! - [`ChunkHasher`] - Incremental hasher trait, implementable by applications
 //! - [`Blake3Hasher`] - BLAKE3 hash implementation (requires `hash-blake3` feature)
+//! - [`Sha256Hasher`] - SHA-256 hash implementation (requires `hash-sha256` feature)
+//! - [`Xxh3Hasher`] - XXH3-128 non-cryptographic hash (requires `hash-xxh3` feature)
+//! - [`RollingChecksum`] - Adler-32 weak checksum for rsync-style matching
 
+mod algorithm;
 #[cfg(feature = "hash-blake3")]
 mod blake3;
+mod hasher;
+mod rolling;
+#[cfg(feature = "hash-sha256")]
+mod sha256;
+#[cfg(feature = "hash-xxh3")]
+mod xxh3;
 
 // Re-export for use within the crate (module is private, so pub is crate-local)
+pub use algorithm::HashAlgorithm;
 #[cfg(feature = "hash-blake3")]
 pub use blake3::Blake3Hasher;
+pub use hasher::{ChunkHasher, HasherFactory};
+pub use rolling::RollingChecksum;
+#[cfg(feature = "hash-sha256")]
+pub use sha256::Sha256Hasher;
+#[cfg(feature = "hash-xxh3")]
+pub use xxh3::Xxh3Hasher;
@@ -0,0 +1,163 @@
+//! Adler-32 weak rolling checksum.
+
+use std::collections::VecDeque;
+
+/// Adler-32 modulus (largest prime below 2^16).
+const MOD: u32 = 65_521;
+
+/// A 32-bit Adler-32 checksum that can slide over a stream one byte at a time.
+///
+/// Used as the cheap *weak* checksum of rsync-style delta matching: compute
+/// it for every chunk (see [`HashConfig::with_weak_checksum`]), then slide a
+/// window of the same length across the new stream and only compute the
+/// strong hash where the weak checksum matches.
+///
+/// Values are standard Adler-32 (RFC 1950), so [`RollingChecksum::checksum`]
+/// of a chunk equals [`Chunk::weak_hash`](crate::Chunk::weak_hash).
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::RollingChecksum;
+///
+/// let data = b"the quick brown fox jumps over the lazy dog";
+/// let target = RollingChecksum::checksum(&data[10..19]);
+///
+/// let mut rolling = RollingChecksum::new(9);
+/// let matches: Vec<usize> = data
+///     .iter()
+///     .enumerate()
+///     .filter_map(|(i, &byte)| rolling.push(byte).filter(|&sum| sum == target).map(|_| i + 1 - 9))
+///     .collect();
+/// assert_eq!(matches, [10]);
+/// ```
+///
+/// [`HashConfig::with_weak_checksum`]: crate::HashConfig::with_weak_checksum
+#[derive(Debug, Clone)]
+pub struct RollingChecksum {
+    a: u32,
+    b: u32,
+    window_len: usize,
+    window: VecDeque<u8>,
+}
+
+impl RollingChecksum {
+    /// Creates a rolling checksum over a window of `window_len` bytes.
+    pub fn new(window_len: usize) -> Self {
+        Self {
+            a: 1,
+            b: 0,
+            window_len,
+            window: VecDeque::with_capacity(window_len),
+        }
+    }
+
+    /// Computes the Adler-32 checksum of `data` in one shot.
+    pub fn checksum(data: &[u8]) -> u32 {
+        Self::extend(1, data)
+    }
+
+    /// Continues a checksum with more data.
+    ///
+    /// `extend(checksum(a), b)` equals `checksum(a ++ b)`; the checksum of no
+    /// data is `1`.
+    pub fn extend(checksum: u32, data: &[u8]) -> u32 {
+        // 5552 is the largest n with 255n(n+1)/2 + (n+1)(MOD-1) < 2^32.
+        let (mut a, mut b) = (checksum & 0xFFFF, checksum >> 16);
+        for block in data.chunks(5552) {
+            for &byte in block {
+                a += u32::from(byte);
+                b += a;
+            }
+            a %= MOD;
+            b %= MOD;
+        }
+        (b << 16) | a
+    }
+
+    /// Slides the window forward by one byte.
+    ///
+    /// Returns the checksum of the last `window_len` bytes once the window is
+    /// full, and `None` while it is still filling (always, for a zero-length
+    /// window).
+    pub fn push(&mut self, byte: u8) -> Option<u32> {
+        if self.window.len() == self.window_len {
+            let out = u32::from(self.window.pop_front()?);
+            // a' = a - out + in; b' = b - len * out - 1 + a'
+            self.a = (self.a + MOD - out) % MOD;
+            let removed = ((self.window_len as u64 * u64::from(out) + 1) % u64::from(MOD)) as u32;
+            self.b = (self.b + MOD - removed) % MOD;
+        }
+        self.window.push_back(byte);
+        self.a = (self.a + u32::from(byte)) % MOD;
+        self.b = (self.b + self.a) % MOD;
+
+        (self.window.len() == self.window_len).then(|| self.value())
+    }
+
+    /// Returns the checksum of the bytes currently in the window.
+    pub fn value(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+
+    /// Returns the window length.
+    pub fn window_len(&self) -> usize {
+        self.window_len
+    }
+
+    /// Clears the window.
+    pub fn reset(&mut self) {
+        self.a = 1;
+        self.b = 0;
+        self.window.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_values() {
+        assert_eq!(RollingChecksum::checksum(b""), 1);
+        assert_eq!(RollingChecksum::checksum(b"Wikipedia"), 0x11E6_0398);
+        assert_eq!(RollingChecksum::checksum(&[0xFF; 100_000]), 0x149A_302C);
+    }
+
+    #[test]
+    fn test_extend() {
+        let data = [7u8; 20_000];
+        let partial = RollingChecksum::checksum(&data[..12_345]);
+        assert_eq!(
+            RollingChecksum::extend(partial, &data[12_345..]),
+            RollingChecksum::checksum(&data)
+        );
+    }
+
+    #[test]
+    fn test_rolling_matches_one_shot() {
+        let data: Vec<u8> = (0..5000u32).map(|i| (i * 31 % 256) as u8).collect();
+        for window_len in [1, 7, 64, 4096] {
+            let mut rolling = RollingChecksum::new(window_len);
+            for (i, &byte) in data.iter().enumerate() {
+                let value = rolling.push(byte);
+                if i + 1 < window_len {
+                    assert_eq!(value, None);
+                } else {
+                    let window = &data[i + 1 - window_len..=i];
+                    assert_eq!(value, Some(RollingChecksum::checksum(window)), "at {}", i);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rolling = RollingChecksum::new(3);
+        rolling.push(1);
+        rolling.push(2);
+        rolling.reset();
+        assert_eq!(rolling.push(3), None);
+        assert_eq!(rolling.value(), RollingChecksum::checksum(&[3]));
+    }
+}
@@ -0,0 +1,147 @@
+//! SHA-256-based chunk hashing implementation.
+//!
+//! This module provides a wrapper around the SHA-256 hash function for
+//! computing chunk hashes that interoperate with other content-addressed
+//! systems.
+//!
+//! # Interoperability
+//!
+//! - **OCI**: a chunk's hex digest equals the `sha256:<hex>` blob digest
+//! - **IPFS**: the digest is the payload of a `sha2-256` multihash
+//! - **Compliance**: FIPS 180-4 approved algorithm
+
+use sha2::{Digest, Sha256};
+
+use super::{ChunkHasher, HashAlgorithm};
+use crate::chunk::ChunkHash;
+
+/// A hasher that computes SHA-256 hashes.
+///
+/// `Sha256Hasher` wraps the `sha2` crate's hasher and provides the same
+/// incremental and one-shot API as [`Blake3Hasher`](super::Blake3Hasher).
+///
+/// # Example
+///
+/// ```ignore
+/// use chunkrs::hash::Sha256Hasher;
+///
+/// let mut hasher = Sha256Hasher::new();
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+/// assert_eq!(hasher.finalize(), Sha256Hasher::hash(b"hello world"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Sha256Hasher {
+    state: Sha256,
+}
+
+impl Sha256Hasher {
+    /// Creates a new hasher.
+    pub fn new() -> Self {
+        Self {
+            state: Sha256::new(),
+        }
+    }
+
+    /// Updates the hasher with more data.
+    pub fn update(&mut self, data: &[u8]) {
+        self.state.update(data);
+    }
+
+    /// Finalizes and returns the hash without consuming the hasher.
+    pub fn finalize(&self) -> ChunkHash {
+        ChunkHash::with_algorithm(HashAlgorithm::Sha256, self.state.clone().finalize().into())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        Digest::reset(&mut self.state);
+    }
+
+    /// Convenience method to hash data in one shot.
+    pub fn hash(data: &[u8]) -> ChunkHash {
+        ChunkHash::with_algorithm(HashAlgorithm::Sha256, Sha256::digest(data).into())
+    }
+}
+
+impl ChunkHasher for Sha256Hasher {
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::Sha256
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        Sha256Hasher::update(self, data);
+    }
+
+    fn finalize(&self) -> ChunkHash {
+        Sha256Hasher::finalize(self)
+    }
+
+    fn reset(&mut self) {
+        Sha256Hasher::reset(self);
+    }
+
+    fn fork(&self) -> Box<dyn ChunkHasher> {
+        Box::new(self.clone())
+    }
+
+    fn digest(&mut self, data: &[u8]) -> ChunkHash {
+        Sha256Hasher::hash(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Known-answer vectors from FIPS 180-4 / NIST CSRC examples.
+    const EMPTY: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+    const ABC: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+    const TWO_BLOCK: &str = "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1";
+
+    #[test]
+    fn test_known_answers() {
+        assert_eq!(Sha256Hasher::hash(b"").to_hex(), EMPTY);
+        assert_eq!(Sha256Hasher::hash(b"abc").to_hex(), ABC);
+        assert_eq!(
+            Sha256Hasher::hash(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")
+                .to_hex(),
+            TWO_BLOCK
+        );
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let hash = Sha256Hasher::hash(b"abc");
+        let parsed = ChunkHash::from_hex_with_algorithm(HashAlgorithm::Sha256, ABC).unwrap();
+
+        assert_eq!(hash, parsed);
+        assert_eq!(parsed.algorithm(), HashAlgorithm::Sha256);
+        assert_ne!(
+            ChunkHash::from_blake3_hex(ABC).unwrap(),
+            hash,
+            "Hex parsed as BLAKE3 must not equal the SHA-256 hash"
+        );
+    }
+
+    #[test]
+    fn test_incremental_hashing() {
+        let mut hasher = Sha256Hasher::new();
+        hasher.update(b"a");
+        hasher.update(b"bc");
+        assert_eq!(hasher.finalize().to_hex(), ABC);
+
+        hasher.reset();
+        assert_eq!(hasher.finalize().to_hex(), EMPTY);
+    }
+
+    #[test]
+    fn test_chunk_hasher_trait() {
+        let mut hasher: Box<dyn ChunkHasher> = Box::new(Sha256Hasher::new());
+        hasher.update(b"stale");
+
+        let hash = hasher.digest(b"abc");
+        assert_eq!(hash.to_hex(), ABC);
+        assert_eq!(hash.algorithm(), HashAlgorithm::Sha256);
+    }
+}
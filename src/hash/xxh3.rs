@@ -0,0 +1,153 @@
+//! XXH3-128-based chunk hashing implementation.
+//!
+//! XXH3 is a fast **non-cryptographic** hash. It is suitable for trusted data
+//! such as in-memory caches, where collision attacks are not a concern.
+//!
+//! Hashes carry [`HashAlgorithm::Xxh3`], which reports
+//! [`is_cryptographic`](HashAlgorithm::is_cryptographic) as `false`, so they
+//! can never be mistaken for BLAKE3 or SHA-256 identities.
+//!
+//! # Digest Encoding
+//!
+//! The 128-bit result is stored in XXH3's canonical big-endian form, so
+//! [`ChunkHash::to_hex`] matches the output of the reference `xxhsum -H2`.
+
+use std::fmt;
+
+use xxhash_rust::xxh3::{Xxh3, xxh3_128};
+
+use super::{ChunkHasher, HashAlgorithm};
+use crate::chunk::ChunkHash;
+
+/// A hasher that computes XXH3-128 hashes.
+///
+/// # Example
+///
+/// ```ignore
+/// use chunkrs::hash::Xxh3Hasher;
+///
+/// let mut hasher = Xxh3Hasher::new();
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+/// assert_eq!(hasher.finalize(), Xxh3Hasher::hash(b"hello world"));
+/// ```
+#[derive(Clone, Default)]
+pub struct Xxh3Hasher {
+    state: Xxh3,
+}
+
+impl Xxh3Hasher {
+    /// Creates a new hasher.
+    pub fn new() -> Self {
+        Self { state: Xxh3::new() }
+    }
+
+    /// Updates the hasher with more data.
+    pub fn update(&mut self, data: &[u8]) {
+        self.state.update(data);
+    }
+
+    /// Finalizes and returns the hash without consuming the hasher.
+    pub fn finalize(&self) -> ChunkHash {
+        Self::to_chunk_hash(self.state.digest128())
+    }
+
+    /// Resets the hasher to its initial state.
+    pub fn reset(&mut self) {
+        self.state.reset();
+    }
+
+    /// Convenience method to hash data in one shot.
+    pub fn hash(data: &[u8]) -> ChunkHash {
+        Self::to_chunk_hash(xxh3_128(data))
+    }
+
+    fn to_chunk_hash(value: u128) -> ChunkHash {
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(&value.to_be_bytes());
+        ChunkHash::with_algorithm(HashAlgorithm::Xxh3, bytes)
+    }
+}
+
+impl fmt::Debug for Xxh3Hasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Xxh3Hasher").finish_non_exhaustive()
+    }
+}
+
+impl ChunkHasher for Xxh3Hasher {
+    fn algorithm(&self) -> HashAlgorithm {
+        HashAlgorithm::Xxh3
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        Xxh3Hasher::update(self, data);
+    }
+
+    fn finalize(&self) -> ChunkHash {
+        Xxh3Hasher::finalize(self)
+    }
+
+    fn reset(&mut self) {
+        Xxh3Hasher::reset(self);
+    }
+
+    fn fork(&self) -> Box<dyn ChunkHasher> {
+        Box::new(self.clone())
+    }
+
+    fn digest(&mut self, data: &[u8]) -> ChunkHash {
+        Xxh3Hasher::hash(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_answer_empty() {
+        assert_eq!(
+            Xxh3Hasher::hash(b"").to_hex(),
+            "99aa06d3014798d86001c324468d497f"
+        );
+    }
+
+    #[test]
+    fn test_digest_is_16_bytes() {
+        let hash = Xxh3Hasher::hash(b"hello world");
+        assert_eq!(hash.digest().len(), 16);
+        assert_eq!(hash.to_hex().len(), 32);
+        assert!(hash.as_bytes()[16..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_not_cryptographic() {
+        let hash = Xxh3Hasher::hash(b"data");
+        assert!(!hash.algorithm().is_cryptographic());
+        assert_ne!(
+            hash,
+            ChunkHash::new(*hash.as_bytes()),
+            "XXH3 hash must never equal a BLAKE3 hash with the same bytes"
+        );
+    }
+
+    #[test]
+    fn test_incremental_hashing() {
+        let mut hasher = Xxh3Hasher::new();
+        hasher.update(b"hello ");
+        hasher.update(b"world");
+        assert_eq!(hasher.finalize(), Xxh3Hasher::hash(b"hello world"));
+
+        hasher.reset();
+        hasher.update(b"abc");
+        assert_eq!(hasher.finalize(), Xxh3Hasher::hash(b"abc"));
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let hash = Xxh3Hasher::hash(b"round trip");
+        let parsed = ChunkHash::from_hex_with_algorithm(HashAlgorithm::Xxh3, &hash.to_hex());
+        assert_eq!(parsed, Some(hash));
+    }
+}
@@ -0,0 +1,85 @@
+//! Read buffer shared by the reading adapters.
+
+use bytes::{Bytes, BytesMut};
+
+/// A zeroed read window whose filled bytes are split off as [`Bytes`].
+///
+/// Reads go into the unused tail of one [`BytesMut`], and only the bytes read
+/// are split off, so small reads share an allocation instead of each pinning
+/// a whole buffer. A new buffer is allocated once less than a quarter of the
+/// current one is left.
+#[derive(Debug)]
+pub struct ReadBuffer {
+    /// Unused tail of the current allocation; emitted bytes share it.
+    buf: BytesMut,
+    size: usize,
+}
+
+impl ReadBuffer {
+    /// Creates a buffer allocating `size` bytes at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "read buffer capacity must be non-zero");
+        Self {
+            buf: BytesMut::new(),
+            size,
+        }
+    }
+
+    /// Returns the window for the next read.
+    ///
+    /// The window is kept until bytes are taken from it, so a read that is
+    /// retried or polled again reuses it.
+    pub fn window(&mut self) -> &mut [u8] {
+        if self.buf.len() <= self.size / 4 {
+            self.buf.clear();
+            self.buf.reserve(self.size);
+            self.buf.resize(self.size, 0);
+        }
+        &mut self.buf
+    }
+
+    /// Splits off the first `n` bytes of the window, filled by the last read.
+    pub fn take(&mut self, n: usize) -> Bytes {
+        self.buf.split_to(n).freeze()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_reads_share_one_allocation() {
+        let mut buffer = ReadBuffer::new(4096);
+        let mut reads = Vec::new();
+        for i in 0..30u8 {
+            buffer.window()[..100].fill(i);
+            reads.push(buffer.take(100));
+        }
+
+        for (i, pair) in reads.windows(2).enumerate() {
+            assert_eq!(pair[0].as_ptr() as usize + 100, pair[1].as_ptr() as usize);
+            assert_eq!(pair[1][..], [i as u8 + 1; 100]);
+        }
+    }
+
+    #[test]
+    fn test_window_is_replaced_when_mostly_used() {
+        let mut buffer = ReadBuffer::new(400);
+        assert_eq!(buffer.window().len(), 400);
+
+        let first = buffer.take(250);
+        assert_eq!(buffer.window().len(), 150);
+
+        let second = buffer.take(100);
+        assert_eq!(buffer.window().len(), 400);
+        assert!(buffer.window().iter().all(|&byte| byte == 0));
+
+        // Bytes already taken keep the old allocation alive.
+        assert_eq!(first.as_ptr() as usize + 250, second.as_ptr() as usize);
+    }
+}
@@ -0,0 +1,120 @@
+//! Tokio codec framing a byte stream into chunks.
+
+use bytes::BytesMut;
+use tokio_util::codec::Decoder;
+
+use crate::chunk::Chunk;
+use crate::chunker::{Chunker, emit_scanned, hold_scanned, scan_boundary};
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+
+/// Frames a tokio byte stream into chunks (requires `tokio` feature).
+///
+/// `FramedRead::new(reader, decoder)` yields `Result<Chunk, ChunkError>`.
+/// The read buffer doubles as the pending buffer: each chunk is split off it
+/// without copying, and bytes already scanned are not rescanned when more
+/// data arrives. At end of input the rest of the buffer goes through
+/// [`Chunker::finish`], so the decoder is ready for another stream.
+///
+/// Scanned bytes wait in the read buffer, not in the chunker, so a
+/// [`Checkpoint`](crate::Checkpoint) of [`ChunkDecoder::chunker`] is only
+/// complete right after a chunk is decoded. Taken in the middle of a chunk,
+/// it is rejected by [`Chunker::restore`].
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::{ChunkConfig, ChunkDecoder};
+/// use futures::StreamExt;
+/// use tokio_util::codec::FramedRead;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let data = vec![5u8; 10_000];
+/// let decoder = ChunkDecoder::new(ChunkConfig::new(256, 1024, 4096)?);
+/// let mut chunks = FramedRead::new(&data[..], decoder);
+///
+/// let mut total = 0;
+/// while let Some(chunk) = chunks.next().await {
+///     total += chunk?.len();
+/// }
+/// assert_eq!(total, 10_000);
+/// # Ok::<(), chunkrs::ChunkError>(())
+/// # }).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChunkDecoder {
+    chunker: Chunker,
+    /// Bytes at the front of the read buffer already scanned.
+    scanned: usize,
+}
+
+impl ChunkDecoder {
+    /// Creates a decoder with the given configuration.
+    ///
+    /// # Panics
+    ///
+    /// Like [`Chunker::new`]; use [`ChunkDecoder::try_new`] to get an error
+    /// instead.
+    pub fn new(config: ChunkConfig) -> Self {
+        Self::from(Chunker::new(config))
+    }
+
+    /// Creates a decoder, rejecting an invalid configuration.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Chunker::try_new`].
+    pub fn try_new(config: ChunkConfig) -> Result<Self, ChunkError> {
+        Chunker::try_new(config).map(Self::from)
+    }
+
+    /// Returns the chunker, for example to inspect its offset.
+    pub fn chunker(&self) -> &Chunker {
+        &self.chunker
+    }
+
+    /// Consumes the decoder, returning the chunker.
+    ///
+    /// Bytes scanned but still in the read buffer are not part of it.
+    pub fn into_inner(self) -> Chunker {
+        self.chunker
+    }
+}
+
+impl From<Chunker> for ChunkDecoder {
+    /// Continues the chunker's stream; its pending bytes start the next chunk.
+    fn from(chunker: Chunker) -> Self {
+        Self {
+            chunker,
+            scanned: 0,
+        }
+    }
+}
+
+impl Decoder for ChunkDecoder {
+    type Item = Chunk;
+    type Error = ChunkError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Chunk>, ChunkError> {
+        match scan_boundary(&mut self.chunker, &src[self.scanned..]) {
+            Some(end) => {
+                let data = src.split_to(self.scanned + end).freeze();
+                self.scanned = 0;
+                Ok(Some(emit_scanned(&mut self.chunker, data)))
+            }
+            None => {
+                self.scanned = src.len();
+                Ok(None)
+            }
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Chunk>, ChunkError> {
+        if let Some(chunk) = self.decode(src)? {
+            return Ok(Some(chunk));
+        }
+        hold_scanned(&mut self.chunker, src.split().freeze());
+        self.scanned = 0;
+        Ok(self.chunker.finish())
+    }
+}
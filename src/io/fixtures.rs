@@ -0,0 +1,35 @@
+//! Test fixtures shared by the I/O adapter tests.
+
+use bytes::Bytes;
+
+use crate::chunk::Chunk;
+use crate::chunker::Chunker;
+use crate::config::ChunkConfig;
+
+/// Small chunk sizes, so the test input spans many chunks.
+pub fn config() -> ChunkConfig {
+    ChunkConfig::new(16, 64, 256).unwrap()
+}
+
+/// 3000 bytes of varied input.
+pub fn data() -> Vec<u8> {
+    (0..3000u32).map(|i| (i * 17 % 256) as u8).collect()
+}
+
+/// The chunks of [`data`] pushed at once through a [`Chunker`].
+pub fn expected() -> Vec<Chunk> {
+    let mut chunker = Chunker::new(config());
+    let chunks = chunker.push_buf(Bytes::from(data()));
+    chunks.into_iter().chain(chunker.finish()).collect()
+}
+
+/// Asserts that `chunks` match [`expected`] in data, offset and hash.
+pub fn assert_expected(chunks: &[Chunk]) {
+    let expected = expected();
+    assert_eq!(chunks.len(), expected.len());
+    for (a, b) in chunks.iter().zip(&expected) {
+        assert_eq!(a.data, b.data);
+        assert_eq!(a.offset, b.offset);
+        assert_eq!(a.hash, b.hash);
+    }
+}
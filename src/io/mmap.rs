@@ -0,0 +1,146 @@
+//! Zero-copy chunking of memory-mapped files.
+
+use bytes::Bytes;
+use memmap2::Mmap;
+
+use crate::chunk::Chunk;
+use crate::chunker::{Chunker, next_chunk};
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+
+/// Chunks a memory-mapped file without copying (requires `mmap` feature).
+///
+/// The mapping is moved into a [`Bytes`] with [`Bytes::from_owner`], and every
+/// emitted [`Chunk::data`] is a slice of it: no chunk is ever copied, and the
+/// mapping is unmapped when the last chunk referencing it is dropped.
+///
+/// Chunks come from a [`Chunker`], so boundaries, hashes, offsets and weak
+/// checksums are identical to pushing the file contents through one. Chunks
+/// are produced lazily by the iterator.
+///
+/// # Safety of the mapping
+///
+/// Mapping a file is `unsafe` in Rust because another process may modify or
+/// truncate it while mapped. `chunkrs` forbids unsafe code, so the caller
+/// creates the [`Mmap`] and upholds that contract.
+///
+/// # Example
+///
+/// ```no_run
+/// use chunkrs::{ChunkConfig, MmapChunks};
+///
+/// let file = std::fs::File::open("large.bin")?;
+/// // SAFETY: the file is not modified while mapped.
+/// let map = unsafe { memmap2::Mmap::map(&file)? };
+///
+/// for chunk in MmapChunks::new(map, ChunkConfig::default()) {
+///     println!("{:?} {}", chunk.offset, chunk.len());
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct MmapChunks {
+    data: Bytes,
+    /// The part of `data` not yet chunked.
+    rest: Bytes,
+    chunker: Chunker,
+}
+
+impl MmapChunks {
+    /// Creates a chunk iterator over a mapping.
+    ///
+    /// # Panics
+    ///
+    /// Like [`Chunker::new`]; use [`MmapChunks::try_new`] to get an error
+    /// instead.
+    pub fn new(map: Mmap, config: ChunkConfig) -> Self {
+        Self::with_chunker(map, Chunker::new(config))
+    }
+
+    /// Creates a chunk iterator over a mapping, rejecting an invalid
+    /// configuration.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Chunker::try_new`].
+    pub fn try_new(map: Mmap, config: ChunkConfig) -> Result<Self, ChunkError> {
+        Ok(Self::with_chunker(map, Chunker::try_new(config)?))
+    }
+
+    fn with_chunker(map: Mmap, chunker: Chunker) -> Self {
+        let data = Bytes::from_owner(map);
+        Self {
+            rest: data.clone(),
+            data,
+            chunker,
+        }
+    }
+
+    /// Returns the mapped contents.
+    pub fn bytes(&self) -> &Bytes {
+        &self.data
+    }
+
+    /// Returns the offset of the next chunk.
+    pub fn offset(&self) -> u64 {
+        self.chunker.offset()
+    }
+
+    /// Returns the chunker, for example to inspect its offset.
+    pub fn chunker(&self) -> &Chunker {
+        &self.chunker
+    }
+}
+
+impl Iterator for MmapChunks {
+    type Item = Chunk;
+
+    fn next(&mut self) -> Option<Chunk> {
+        next_chunk(&mut self.chunker, &mut self.rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::HashConfig;
+    use memmap2::MmapMut;
+
+    fn mapped(data: &[u8]) -> Mmap {
+        let mut map = MmapMut::map_anon(data.len()).unwrap();
+        map.copy_from_slice(data);
+        map.make_read_only().unwrap()
+    }
+
+    #[test]
+    fn test_matches_chunker_without_copies() {
+        let data: Vec<u8> = (0..20_000u32).map(|i| (i * 37 % 256) as u8).collect();
+        let config = ChunkConfig::new(64, 256, 1024)
+            .unwrap()
+            .with_hash_config(HashConfig::enabled().with_weak_checksum(true));
+
+        let mut chunker = Chunker::new(config);
+        let chunks = chunker.push_buf(Bytes::from(data.clone()));
+        let expected: Vec<Chunk> = chunks.into_iter().chain(chunker.finish()).collect();
+
+        let chunks = MmapChunks::new(mapped(&data), config);
+        let base = chunks.bytes().as_ptr() as usize;
+        let actual: Vec<Chunk> = chunks.collect();
+
+        assert_eq!(actual.len(), expected.len());
+        for (a, b) in actual.iter().zip(&expected) {
+            assert_eq!(a.data, b.data);
+            assert_eq!(a.offset, b.offset);
+            assert_eq!(a.hash, b.hash);
+            assert_eq!(a.weak_hash, b.weak_hash);
+            // Zero-copy: each chunk points into the mapping at its offset.
+            assert_eq!(a.data.as_ptr() as usize - base, a.start() as usize);
+        }
+    }
+
+    #[test]
+    fn test_empty_mapping() {
+        let map = MmapMut::map_anon(0).unwrap().make_read_only().unwrap();
+        assert_eq!(MmapChunks::new(map, ChunkConfig::default()).count(), 0);
+    }
+}
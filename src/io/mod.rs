@@ -0,0 +1,29 @@
+//! Adapters between chunking and I/O traits.
+//!
+//! - [`ChunkReader`] - Iterator of chunks over any [`std::io::Read`]
+//! - [`ChunkStream`] - Runtime-agnostic `Stream` of chunks over `futures::io::AsyncRead`
+//! - [`MmapChunks`] - Zero-copy chunks of a memory-mapped file (requires `mmap` feature)
+//! - [`ChunkDecoder`] - `tokio_util` codec framing a tokio reader into chunks (requires `tokio` feature)
+//! - [`ChunkWriter`] - [`std::io::Write`] sink delivering chunks to a [`ChunkSink`]
+
+mod buffer;
+#[cfg(feature = "tokio")]
+mod decoder;
+#[cfg(test)]
+mod fixtures;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod reader;
+#[cfg(feature = "futures")]
+mod stream;
+mod writer;
+
+// Re-export for use within the crate (module is private, so pub is crate-local)
+#[cfg(feature = "tokio")]
+pub use decoder::ChunkDecoder;
+#[cfg(feature = "mmap")]
+pub use mmap::MmapChunks;
+pub use reader::ChunkReader;
+#[cfg(feature = "futures")]
+pub use stream::ChunkStream;
+pub use writer::{ChunkSink, ChunkWriter};
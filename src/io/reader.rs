@@ -0,0 +1,243 @@
+//! Chunk iterator over a blocking reader.
+
+use std::collections::VecDeque;
+use std::io::{self, Read};
+
+use crate::chunk::Chunk;
+use crate::chunker::Chunker;
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+
+use super::buffer::ReadBuffer;
+
+/// Default read buffer size (256 KiB).
+const DEFAULT_BUF_SIZE: usize = 256 * 1024;
+
+/// Chunks everything read from a [`Read`] source.
+///
+/// Iterates over `Result<Chunk, ChunkError>`: each read is pushed into a
+/// [`Chunker`], and the final chunk is emitted at end of file. Chunks are
+/// sliced from the read buffer without copying, except for chunks that span
+/// two reads.
+///
+/// Small reads share one buffer allocation instead of each pinning a whole
+/// buffer; a new one is allocated once less than a quarter is left.
+///
+/// Read errors are returned as [`ChunkError::Io`]; reads interrupted with
+/// [`io::ErrorKind::Interrupted`] are retried. After an error, calling
+/// `next()` again retries the read.
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::{ChunkConfig, ChunkReader};
+///
+/// let data = vec![42u8; 10_000];
+/// let reader = ChunkReader::new(&data[..], ChunkConfig::new(256, 1024, 4096)?);
+///
+/// let mut total = 0;
+/// for chunk in reader {
+///     total += chunk?.len();
+/// }
+/// assert_eq!(total, 10_000);
+/// # Ok::<(), chunkrs::ChunkError>(())
+/// ```
+#[derive(Debug)]
+pub struct ChunkReader<R> {
+    reader: R,
+    chunker: Chunker,
+    buf: ReadBuffer,
+    ready: VecDeque<Chunk>,
+    done: bool,
+}
+
+impl<R: Read> ChunkReader<R> {
+    /// Creates a chunk iterator with a 256 KiB read buffer.
+    ///
+    /// # Panics
+    ///
+    /// Like [`Chunker::new`](crate::Chunker::new); use
+    /// [`ChunkReader::try_new`] to get an error instead.
+    pub fn new(reader: R, config: ChunkConfig) -> Self {
+        Self::with_capacity(DEFAULT_BUF_SIZE, reader, config)
+    }
+
+    /// Creates a chunk iterator with a 256 KiB read buffer, rejecting an invalid
+    /// configuration.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Chunker::try_new`].
+    pub fn try_new(reader: R, config: ChunkConfig) -> Result<Self, ChunkError> {
+        Self::try_with_capacity(DEFAULT_BUF_SIZE, reader, config)
+    }
+
+    /// Creates a chunk iterator that reads up to `capacity` bytes at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero, or like [`Chunker::new`](crate::Chunker::new).
+    pub fn with_capacity(capacity: usize, reader: R, config: ChunkConfig) -> Self {
+        Self::with_chunker(capacity, reader, Chunker::new(config))
+    }
+
+    /// Creates a chunk iterator that reads up to `capacity` bytes at a time,
+    /// rejecting an invalid configuration.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Chunker::try_new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn try_with_capacity(
+        capacity: usize,
+        reader: R,
+        config: ChunkConfig,
+    ) -> Result<Self, ChunkError> {
+        Ok(Self::with_chunker(
+            capacity,
+            reader,
+            Chunker::try_new(config)?,
+        ))
+    }
+
+    fn with_chunker(capacity: usize, reader: R, chunker: Chunker) -> Self {
+        Self {
+            reader,
+            chunker,
+            buf: ReadBuffer::new(capacity),
+            ready: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns the chunker, for example to inspect its offset.
+    pub fn chunker(&self) -> &Chunker {
+        &self.chunker
+    }
+
+    /// Consumes the adapter, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads once and queues any completed chunks.
+    fn fill(&mut self) -> io::Result<()> {
+        let n = loop {
+            match self.reader.read(self.buf.window()) {
+                Ok(n) => break n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        };
+
+        if n == 0 {
+            self.done = true;
+            self.ready.extend(self.chunker.finish());
+        } else {
+            let data = self.buf.take(n);
+            self.ready.extend(self.chunker.push_buf(data));
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for ChunkReader<R> {
+    type Item = Result<Chunk, ChunkError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(chunk) = self.ready.pop_front() {
+                return Some(Ok(chunk));
+            }
+            if self.done {
+                return None;
+            }
+            if let Err(err) = self.fill() {
+                return Some(Err(ChunkError::from(err)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::fixtures::{assert_expected, config, data, expected};
+
+    /// Returns at most `step` bytes per read and fails once at `fail_at`.
+    struct Trickle<'a> {
+        data: &'a [u8],
+        step: usize,
+        fail_at: Option<usize>,
+        pos: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.fail_at.is_some_and(|at| self.pos >= at) {
+                self.fail_at = None;
+                return Err(io::Error::other("disk on fire"));
+            }
+            let n = self.step.min(buf.len()).min(self.data.len() - self.pos);
+            buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_matches_push_for_any_buffer_size() {
+        let data = data();
+        for capacity in [1, 13, 256, 4096] {
+            let chunks: Vec<Chunk> = ChunkReader::with_capacity(capacity, &data[..], config())
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_expected(&chunks);
+        }
+    }
+
+    #[test]
+    fn test_empty_reader() {
+        let mut reader = ChunkReader::new(io::empty(), config());
+        assert!(reader.next().is_none());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_error_is_reported_and_retryable() {
+        let data = data();
+        let trickle = Trickle {
+            data: &data,
+            step: 100,
+            fail_at: Some(1000),
+            pos: 0,
+        };
+        let mut reader = ChunkReader::with_capacity(4096, trickle, config());
+
+        let mut chunks = Vec::new();
+        let mut errors = 0;
+        for item in reader.by_ref() {
+            match item {
+                Ok(chunk) => chunks.push(chunk),
+                Err(ChunkError::Io { .. }) => errors += 1,
+                Err(err) => panic!("unexpected error: {}", err),
+            }
+        }
+        assert_eq!(errors, 1);
+        assert_eq!(chunks.len(), expected().len());
+        assert_eq!(reader.chunker().offset(), 3000);
+    }
+}
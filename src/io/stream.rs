@@ -0,0 +1,227 @@
+//! Runtime-agnostic chunk stream over `futures::io::AsyncRead`.
+
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use futures_io::AsyncRead;
+
+use crate::chunk::Chunk;
+use crate::chunker::Chunker;
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+
+use super::buffer::ReadBuffer;
+
+/// Default read buffer size (256 KiB).
+const DEFAULT_BUF_SIZE: usize = 256 * 1024;
+
+/// Chunks everything read from a [`futures_io::AsyncRead`] source (requires
+/// `futures` feature).
+///
+/// A [`Stream`] of `Result<Chunk, ChunkError>` driving the same [`Chunker`]
+/// state machine as the blocking [`ChunkReader`](crate::ChunkReader). It does
+/// not depend on any runtime.
+///
+/// The source is only read when the consumer polls and no completed chunks
+/// are queued, so a slow consumer applies backpressure all the way to the
+/// reader. At most one read buffer plus the chunks it produced are held.
+///
+/// As in [`ChunkReader`](crate::ChunkReader), small reads share one buffer
+/// allocation.
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::{ChunkConfig, ChunkStream};
+/// use futures::{StreamExt, executor::block_on, io::Cursor};
+///
+/// let reader = Cursor::new(vec![9u8; 10_000]);
+/// let mut stream = ChunkStream::new(reader, ChunkConfig::new(256, 1024, 4096)?);
+///
+/// let total = block_on(async {
+///     let mut total = 0;
+///     while let Some(chunk) = stream.next().await {
+///         total += chunk?.len();
+///     }
+///     Ok::<_, chunkrs::ChunkError>(total)
+/// })?;
+/// assert_eq!(total, 10_000);
+/// # Ok::<(), chunkrs::ChunkError>(())
+/// ```
+#[derive(Debug)]
+pub struct ChunkStream<R> {
+    reader: R,
+    chunker: Chunker,
+    buf: ReadBuffer,
+    ready: VecDeque<Chunk>,
+    done: bool,
+}
+
+impl<R: AsyncRead + Unpin> ChunkStream<R> {
+    /// Creates a chunk stream with a 256 KiB read buffer.
+    ///
+    /// # Panics
+    ///
+    /// Like [`Chunker::new`](crate::Chunker::new); use
+    /// [`ChunkStream::try_new`] to get an error instead.
+    pub fn new(reader: R, config: ChunkConfig) -> Self {
+        Self::with_capacity(DEFAULT_BUF_SIZE, reader, config)
+    }
+
+    /// Creates a chunk stream with a 256 KiB read buffer, rejecting an invalid
+    /// configuration.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Chunker::try_new`].
+    pub fn try_new(reader: R, config: ChunkConfig) -> Result<Self, ChunkError> {
+        Self::try_with_capacity(DEFAULT_BUF_SIZE, reader, config)
+    }
+
+    /// Creates a chunk stream that reads up to `capacity` bytes at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero, or like [`Chunker::new`](crate::Chunker::new).
+    pub fn with_capacity(capacity: usize, reader: R, config: ChunkConfig) -> Self {
+        Self::with_chunker(capacity, reader, Chunker::new(config))
+    }
+
+    /// Creates a chunk stream that reads up to `capacity` bytes at a time,
+    /// rejecting an invalid configuration.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Chunker::try_new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn try_with_capacity(
+        capacity: usize,
+        reader: R,
+        config: ChunkConfig,
+    ) -> Result<Self, ChunkError> {
+        Ok(Self::with_chunker(
+            capacity,
+            reader,
+            Chunker::try_new(config)?,
+        ))
+    }
+
+    fn with_chunker(capacity: usize, reader: R, chunker: Chunker) -> Self {
+        Self {
+            reader,
+            chunker,
+            buf: ReadBuffer::new(capacity),
+            ready: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns the chunker, for example to inspect its offset.
+    pub fn chunker(&self) -> &Chunker {
+        &self.chunker
+    }
+
+    /// Consumes the stream, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for ChunkStream<R> {
+    type Item = Result<Chunk, ChunkError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(chunk) = this.ready.pop_front() {
+                return Poll::Ready(Some(Ok(chunk)));
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            // The window is kept across `Pending`; the bytes read are split
+            // off it, so completed chunks can slice it without copying.
+            match Pin::new(&mut this.reader).poll_read(cx, this.buf.window()) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(err)) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Poll::Ready(Err(err)) => return Poll::Ready(Some(Err(ChunkError::from(err)))),
+                Poll::Ready(Ok(0)) => {
+                    this.done = true;
+                    this.ready.extend(this.chunker.finish());
+                }
+                Poll::Ready(Ok(n)) => {
+                    let data = this.buf.take(n);
+                    this.ready.extend(this.chunker.push_buf(data));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::fixtures::{assert_expected, config, data};
+    use futures::executor::block_on;
+    use futures::io::Cursor;
+    use futures::{StreamExt, TryStreamExt};
+
+    /// Alternates between `Pending` and reading at most `step` bytes.
+    struct Hesitant {
+        inner: Cursor<Vec<u8>>,
+        step: usize,
+        ready: bool,
+    }
+
+    impl AsyncRead for Hesitant {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            self.ready = !self.ready;
+            if !self.ready {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            let step = self.step.min(buf.len());
+            Pin::new(&mut self.inner).poll_read(cx, &mut buf[..step])
+        }
+    }
+
+    #[test]
+    fn test_matches_push_with_pending_reads() {
+        for step in [1, 50, 4096] {
+            let reader = Hesitant {
+                inner: Cursor::new(data()),
+                step,
+                ready: false,
+            };
+            let stream = ChunkStream::with_capacity(512, reader, config());
+            let chunks: Vec<Chunk> = block_on(stream.try_collect()).unwrap();
+            assert_expected(&chunks);
+        }
+    }
+
+    #[test]
+    fn test_reads_only_on_demand() {
+        let mut stream = ChunkStream::with_capacity(100, Cursor::new(data()), config());
+        let first = block_on(stream.next()).unwrap().unwrap();
+
+        // Only enough input for the first chunk has been read.
+        let read = stream.get_ref().position();
+        assert!(read <= first.len() as u64 + 100, "read {} bytes", read);
+    }
+}
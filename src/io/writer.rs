@@ -0,0 +1,205 @@
+//! `io::Write` adapter that chunks everything written to it.
+
+use std::io::{self, Write};
+use std::sync::mpsc;
+
+use bytes::Bytes;
+
+use crate::chunk::Chunk;
+use crate::chunker::{Chunker, StreamSummary};
+use crate::config::ChunkConfig;
+use crate::error::ChunkError;
+
+/// Destination for the chunks produced by a [`ChunkWriter`].
+///
+/// Implemented for closures taking a [`Chunk`] and for
+/// [`mpsc::Sender`]/[`mpsc::SyncSender`] of chunks. Sending on a channel whose
+/// receiver is gone fails with [`io::ErrorKind::BrokenPipe`].
+pub trait ChunkSink {
+    /// Delivers one chunk.
+    fn deliver(&mut self, chunk: Chunk) -> io::Result<()>;
+}
+
+impl<F: FnMut(Chunk)> ChunkSink for F {
+    fn deliver(&mut self, chunk: Chunk) -> io::Result<()> {
+        self(chunk);
+        Ok(())
+    }
+}
+
+impl ChunkSink for mpsc::Sender<Chunk> {
+    fn deliver(&mut self, chunk: Chunk) -> io::Result<()> {
+        self.send(chunk).map_err(|_| receiver_gone())
+    }
+}
+
+impl ChunkSink for mpsc::SyncSender<Chunk> {
+    fn deliver(&mut self, chunk: Chunk) -> io::Result<()> {
+        self.send(chunk).map_err(|_| receiver_gone())
+    }
+}
+
+fn receiver_gone() -> io::Error {
+    io::Error::new(io::ErrorKind::BrokenPipe, "chunk receiver dropped")
+}
+
+/// Chunks everything written to it, delivering chunks to a [`ChunkSink`].
+///
+/// Lets producers that write into something (archivers, serializers,
+/// compressors) feed a [`Chunker`] directly. Completed chunks are delivered as
+/// soon as their boundary is found; [`finish`](Self::finish) emits the final
+/// chunk and returns the [`StreamSummary`].
+///
+/// [`flush`](Write::flush) does not cut a chunk: boundaries depend only on
+/// content, so flushing at different points must not change them. Dropping
+/// the writer without calling `finish()` still delivers the final chunk,
+/// ignoring errors.
+///
+/// Written data is copied once into the chunker, since `write()` only borrows
+/// it.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Write;
+/// use std::sync::mpsc;
+/// use chunkrs::{ChunkConfig, ChunkWriter};
+///
+/// let (tx, rx) = mpsc::channel();
+/// let mut writer = ChunkWriter::new(tx, ChunkConfig::new(256, 1024, 4096)?);
+///
+/// for _ in 0..10 {
+///     writer.write_all(&[7u8; 1000])?;
+/// }
+/// let summary = writer.finish()?;
+///
+/// let chunks: Vec<_> = rx.iter().collect();
+/// assert_eq!(summary.len, 10_000);
+/// assert_eq!(summary.chunk_count, chunks.len() as u64);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct ChunkWriter<S: ChunkSink> {
+    chunker: Chunker,
+    sink: S,
+    finished: bool,
+}
+
+impl<S: ChunkSink> ChunkWriter<S> {
+    /// Creates a writer delivering chunks to `sink`.
+    ///
+    /// # Panics
+    ///
+    /// Like [`Chunker::new`](crate::Chunker::new); use
+    /// [`ChunkWriter::try_new`] to get an error instead.
+    pub fn new(sink: S, config: ChunkConfig) -> Self {
+        Self::with_chunker(sink, Chunker::new(config))
+    }
+
+    /// Creates a writer delivering chunks to `sink`, rejecting an invalid
+    /// configuration.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Chunker::try_new`].
+    pub fn try_new(sink: S, config: ChunkConfig) -> Result<Self, ChunkError> {
+        Ok(Self::with_chunker(sink, Chunker::try_new(config)?))
+    }
+
+    fn with_chunker(sink: S, chunker: Chunker) -> Self {
+        Self {
+            chunker,
+            sink,
+            finished: false,
+        }
+    }
+
+    /// Returns a reference to the sink.
+    pub fn get_ref(&self) -> &S {
+        &self.sink
+    }
+
+    /// Returns the chunker, for example to inspect its offset.
+    pub fn chunker(&self) -> &Chunker {
+        &self.chunker
+    }
+
+    /// Emits the final chunk and returns the stream summary.
+    pub fn finish(mut self) -> io::Result<StreamSummary> {
+        self.close()
+    }
+
+    fn close(&mut self) -> io::Result<StreamSummary> {
+        self.finished = true;
+        let (final_chunk, summary) = self.chunker.finish_with_summary();
+        if let Some(chunk) = final_chunk {
+            self.sink.deliver(chunk)?;
+        }
+        Ok(summary)
+    }
+}
+
+impl<S: ChunkSink> Write for ChunkWriter<S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.finished {
+            return Err(io::Error::other("write after finish"));
+        }
+        for chunk in self.chunker.push_buf(Bytes::copy_from_slice(buf)) {
+            self.sink.deliver(chunk)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<S: ChunkSink> Drop for ChunkWriter<S> {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = self.close();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::fixtures::{assert_expected, config, data};
+
+    #[test]
+    fn test_callback_matches_push() {
+        let mut delivered = Vec::new();
+        let mut writer = ChunkWriter::new(|chunk| delivered.push(chunk), config());
+        for piece in data().chunks(77) {
+            writer.write_all(piece).unwrap();
+            writer.flush().unwrap();
+        }
+        let summary = writer.finish().unwrap();
+
+        assert_eq!(summary.len, 3000);
+        assert_expected(&delivered);
+    }
+
+    #[test]
+    fn test_drop_delivers_final_chunk() {
+        let (tx, rx) = mpsc::channel();
+        {
+            let mut writer = ChunkWriter::new(tx, config());
+            writer.write_all(b"short").unwrap();
+        }
+        let chunks: Vec<Chunk> = rx.iter().collect();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(&chunks[0].data[..], b"short");
+    }
+
+    #[test]
+    fn test_dropped_receiver_is_broken_pipe() {
+        let (tx, rx) = mpsc::sync_channel(16);
+        drop(rx);
+        let mut writer = ChunkWriter::new(tx, config());
+        let err = writer.write_all(&data()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    }
+}
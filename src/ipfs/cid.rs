@@ -0,0 +1,168 @@
+//! CIDv1 content identifiers.
+
+use std::fmt;
+
+use crate::chunk::{ChunkHash, ChunkId};
+use crate::util;
+
+/// A version 1 IPFS content identifier.
+///
+/// Pairs a content codec (what the block is) with a multihash (its
+/// [`ChunkId`]):
+///
+/// ```text
+/// <varint 1><varint codec><multihash>
+/// ```
+///
+/// Chunks are addressed as `raw` blocks ([`Cid::RAW`]), exactly as
+/// `ipfs add --raw-leaves` stores them; UnixFS nodes use [`Cid::DAG_PB`].
+/// [`Display`](fmt::Display) gives the usual base32 form (`bafk…` for raw
+/// SHA-256 blocks).
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::{ChunkHash, Cid, HashAlgorithm};
+///
+/// // SHA-256 of the empty string
+/// let hash = ChunkHash::from_hex_with_algorithm(
+///     HashAlgorithm::Sha256,
+///     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+/// )
+/// .unwrap();
+///
+/// let cid = Cid::raw(hash);
+/// assert_eq!(
+///     cid.to_string(),
+///     "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"
+/// );
+/// assert_eq!(Cid::from_multibase(&cid.to_string()), Some(cid));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cid {
+    codec: u64,
+    hash: ChunkId,
+}
+
+impl Cid {
+    /// Multicodec code for raw binary blocks.
+    pub const RAW: u64 = 0x55;
+
+    /// Multicodec code for dag-pb (UnixFS) nodes.
+    pub const DAG_PB: u64 = 0x70;
+
+    /// Creates a CIDv1 from a codec and multihash.
+    pub const fn new(codec: u64, hash: ChunkId) -> Self {
+        Self { codec, hash }
+    }
+
+    /// Creates a `raw` CID for a chunk's hash.
+    pub fn raw(hash: ChunkHash) -> Self {
+        Self::new(Self::RAW, ChunkId::from(hash))
+    }
+
+    /// Returns the CID version (always 1).
+    pub fn version(&self) -> u64 {
+        1
+    }
+
+    /// Returns the content codec.
+    pub fn codec(&self) -> u64 {
+        self.codec
+    }
+
+    /// Returns the multihash.
+    pub fn hash(&self) -> &ChunkId {
+        &self.hash
+    }
+
+    /// Encodes the CID in binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(4 + self.hash.digest().len() + 4);
+        util::write_varint(self.version(), &mut out);
+        util::write_varint(self.codec, &mut out);
+        out.extend_from_slice(&self.hash.to_bytes());
+        out
+    }
+
+    /// Decodes a binary CIDv1.
+    ///
+    /// Returns `None` for other versions, malformed varints or trailing bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (version, version_len) = util::read_varint(bytes)?;
+        if version != 1 {
+            return None;
+        }
+        let (codec, codec_len) = util::read_varint(&bytes[version_len..])?;
+        let hash = ChunkId::from_bytes(&bytes[version_len + codec_len..])?;
+        Some(Self::new(codec, hash))
+    }
+
+    /// Encodes as multibase base32 (lowercase, unpadded, `b` prefix).
+    pub fn to_base32(&self) -> String {
+        format!("b{}", util::encode_base32(&self.to_bytes()))
+    }
+
+    /// Decodes a multibase string.
+    ///
+    /// Supports base16 (`f`/`F`), base32 (`b`/`B`) and base58btc (`z`).
+    pub fn from_multibase(text: &str) -> Option<Self> {
+        let mut chars = text.chars();
+        let prefix = chars.next()?;
+        let body = chars.as_str();
+        let bytes = match prefix {
+            'f' | 'F' => util::decode_hex(body)?,
+            'b' | 'B' => util::decode_base32(body)?,
+            'z' => util::decode_base58(body)?,
+            _ => return None,
+        };
+        Self::from_bytes(&bytes)
+    }
+}
+
+impl fmt::Display for Cid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_base32())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::HashAlgorithm;
+
+    fn sha256_of_hello_world() -> ChunkHash {
+        ChunkHash::from_hex_with_algorithm(
+            HashAlgorithm::Sha256,
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_raw_cid_known_value() {
+        let cid = Cid::raw(sha256_of_hello_world());
+        assert_eq!(
+            cid.to_string(),
+            "bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e"
+        );
+        assert_eq!(cid.version(), 1);
+        assert_eq!(cid.codec(), Cid::RAW);
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let cid = Cid::raw(ChunkHash::new([7u8; 32]));
+        let bytes = cid.to_bytes();
+        assert_eq!(&bytes[..4], &[0x01, 0x55, 0x1e, 0x20]);
+        assert_eq!(Cid::from_bytes(&bytes), Some(cid));
+    }
+
+    #[test]
+    fn test_rejects_other_versions() {
+        let mut bytes = Cid::raw(sha256_of_hello_world()).to_bytes();
+        bytes[0] = 0x00;
+        assert!(Cid::from_bytes(&bytes).is_none());
+        assert!(Cid::from_multibase("x123").is_none());
+    }
+}
@@ -0,0 +1,12 @@
+//! IPFS content identifiers and UnixFS DAGs.
+//!
+//! - [`Cid`] - CIDv1 for raw chunk blocks and dag-pb nodes
+//! - [`UnixFsDag`] - Balanced UnixFS file DAG over a stream's chunks
+
+mod cid;
+mod pb;
+mod unixfs;
+
+// Re-export for use within the crate (module is private, so pub is crate-local)
+pub use cid::Cid;
+pub use unixfs::UnixFsDag;
@@ -0,0 +1,35 @@
+//! Minimal protobuf encoding for dag-pb and UnixFS messages.
+
+use crate::util;
+
+const WIRE_VARINT: u64 = 0;
+const WIRE_LEN: u64 = 2;
+
+/// Appends a varint field.
+pub fn write_uint(field: u64, value: u64, out: &mut Vec<u8>) {
+    util::write_varint((field << 3) | WIRE_VARINT, out);
+    util::write_varint(value, out);
+}
+
+/// Appends a length-delimited field.
+pub fn write_bytes(field: u64, value: &[u8], out: &mut Vec<u8>) {
+    util::write_varint((field << 3) | WIRE_LEN, out);
+    util::write_varint(value.len() as u64, out);
+    out.extend_from_slice(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_unixfs_file() {
+        // UnixFS Data { Type: File, filesize: 0 } wrapped in a PBNode.
+        let mut data = Vec::new();
+        write_uint(1, 2, &mut data);
+        write_uint(3, 0, &mut data);
+        let mut node = Vec::new();
+        write_bytes(1, &data, &mut node);
+        assert_eq!(node, [0x0a, 0x04, 0x08, 0x02, 0x18, 0x00]);
+    }
+}
@@ -0,0 +1,313 @@
+//! Balanced UnixFS file DAG builder.
+
+use super::Cid;
+use super::pb;
+use crate::chunk::{Chunk, ChunkId};
+use crate::error::ChunkError;
+use crate::hash::{ChunkHasher, HashAlgorithm, Sha256Hasher};
+
+/// UnixFS `Data.Type` for files.
+const UNIXFS_FILE: u64 = 2;
+
+/// A link from a UnixFS node to a child block.
+#[derive(Debug, Clone, Copy)]
+struct Link {
+    cid: Cid,
+    /// File bytes under the child.
+    size: u64,
+    /// Cumulative encoded size of the child's DAG (dag-pb `Tsize`).
+    tsize: u64,
+}
+
+/// Builds a UnixFS file DAG from the ordered chunks of a stream.
+///
+/// Each chunk becomes a `raw` leaf addressed by its own hash (see
+/// [`Chunk::cid`]). Leaves are grouped under dag-pb nodes of at most
+/// [`fanout`](Self::with_fanout) links, level by level, until a single root
+/// remains: the balanced layout `ipfs add --cid-version=1 --raw-leaves` uses.
+/// With SHA-256 chunk hashes, the default fan-out of 174 and the same chunk
+/// boundaries, the root CID matches what an IPFS node would compute for the
+/// file.
+///
+/// A stream with a single chunk has that chunk's CID as its root; an empty
+/// stream has the CID of an empty `raw` block.
+///
+/// Everything happens in memory; [`nodes`](Self::nodes) returns the encoded
+/// interior blocks for export.
+///
+/// # Example
+///
+/// ```
+/// use chunkrs::{ChunkConfig, Chunker, HashAlgorithm, HashConfig, UnixFsDag};
+/// use bytes::Bytes;
+///
+/// let config = ChunkConfig::new(64, 256, 1024)?
+///     .with_hash_config(HashConfig::enabled().with_algorithm(HashAlgorithm::Sha256));
+/// let mut chunker = Chunker::new(config);
+/// let mut dag = UnixFsDag::new().with_fanout(4);
+///
+/// let chunks = chunker.push_buf(Bytes::from(vec![3u8; 5000]));
+/// for chunk in chunks.iter().chain(chunker.finish().iter()) {
+///     dag.push_chunk(chunk)?;
+/// }
+///
+/// let root = dag.root();
+/// assert!(root.to_string().starts_with("bafybei"));
+/// # Ok::<(), chunkrs::ChunkError>(())
+/// ```
+#[derive(Debug)]
+pub struct UnixFsDag {
+    hasher: Box<dyn ChunkHasher>,
+    fanout: usize,
+    leaves: Vec<Link>,
+    len: u64,
+}
+
+impl UnixFsDag {
+    /// Default maximum number of links per node (matches IPFS).
+    pub const DEFAULT_FANOUT: usize = 174;
+
+    /// Creates an empty DAG whose nodes are hashed with SHA-256.
+    pub fn new() -> Self {
+        Self::with_hasher(Box::new(Sha256Hasher::new()))
+    }
+
+    /// Creates an empty DAG whose nodes are hashed with `hasher`.
+    pub fn with_hasher(hasher: Box<dyn ChunkHasher>) -> Self {
+        Self {
+            hasher,
+            fanout: Self::DEFAULT_FANOUT,
+            leaves: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Sets the maximum number of links per node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fanout` is less than 2.
+    pub fn with_fanout(mut self, fanout: usize) -> Self {
+        assert!(fanout >= 2, "fanout must be at least 2");
+        self.fanout = fanout;
+        self
+    }
+
+    /// Returns the maximum number of links per node.
+    pub fn fanout(&self) -> usize {
+        self.fanout
+    }
+
+    /// Returns the algorithm used for dag-pb nodes.
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.hasher.algorithm()
+    }
+
+    /// Appends the next chunk of the stream as a `raw` leaf.
+    ///
+    /// Returns an error if the chunk has no hash, or if its offset is set and
+    /// does not equal the total length of the chunks added so far.
+    pub fn push_chunk(&mut self, chunk: &Chunk) -> Result<(), ChunkError> {
+        let cid = chunk.cid().ok_or(ChunkError::InvalidInput {
+            message: "chunk has no hash",
+        })?;
+        if chunk.offset.is_some_and(|offset| offset != self.len) {
+            return Err(ChunkError::InvalidInput {
+                message: "chunk offset does not continue the stream",
+            });
+        }
+        self.push_cid(chunk.len() as u64, cid);
+        Ok(())
+    }
+
+    /// Appends a leaf block of `len` file bytes.
+    pub fn push_cid(&mut self, len: u64, cid: Cid) {
+        self.leaves.push(Link {
+            cid,
+            size: len,
+            tsize: len,
+        });
+        self.len += len;
+    }
+
+    /// Returns the number of leaves (chunks).
+    pub fn leaf_count(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns `true` if no chunks have been added.
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Returns the total length of the chunks added so far.
+    pub fn stream_len(&self) -> u64 {
+        self.len
+    }
+
+    /// Computes the root CID.
+    pub fn root(&mut self) -> Cid {
+        self.build(None)
+    }
+
+    /// Returns the encoded dag-pb nodes with their CIDs, root last.
+    ///
+    /// Leaves are not included: they are the chunk data itself.
+    pub fn nodes(&mut self) -> Vec<(Cid, Vec<u8>)> {
+        let mut nodes = Vec::new();
+        self.build(Some(&mut nodes));
+        nodes
+    }
+
+    fn build(&mut self, mut out: Option<&mut Vec<(Cid, Vec<u8>)>>) -> Cid {
+        if self.leaves.is_empty() {
+            return Cid::raw(self.hasher.digest(&[]));
+        }
+        let mut level = self.leaves.clone();
+        while level.len() > 1 {
+            level = level
+                .chunks(self.fanout)
+                .map(|links| {
+                    let node = encode_node(links);
+                    let cid = Cid::new(Cid::DAG_PB, ChunkId::from(self.hasher.digest(&node)));
+                    let link = Link {
+                        cid,
+                        size: links.iter().map(|link| link.size).sum(),
+                        tsize: node.len() as u64 + links.iter().map(|link| link.tsize).sum::<u64>(),
+                    };
+                    if let Some(out) = out.as_deref_mut() {
+                        out.push((cid, node));
+                    }
+                    link
+                })
+                .collect();
+        }
+        level[0].cid
+    }
+}
+
+impl Default for UnixFsDag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Encodes a dag-pb node for a UnixFS file with the given children.
+///
+/// Field order follows the canonical dag-pb form: links, then data.
+fn encode_node(links: &[Link]) -> Vec<u8> {
+    let mut data = Vec::new();
+    pb::write_uint(1, UNIXFS_FILE, &mut data);
+    pb::write_uint(3, links.iter().map(|link| link.size).sum(), &mut data);
+    for link in links {
+        pb::write_uint(4, link.size, &mut data);
+    }
+
+    let mut node = Vec::new();
+    for link in links {
+        let mut encoded = Vec::new();
+        pb::write_bytes(1, &link.cid.to_bytes(), &mut encoded);
+        pb::write_bytes(2, b"", &mut encoded);
+        pb::write_uint(3, link.tsize, &mut encoded);
+        pb::write_bytes(2, &encoded, &mut node);
+    }
+    pb::write_bytes(1, &data, &mut node);
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk_of(data: &[u8]) -> Chunk {
+        Chunk::new(data.to_vec()).set_hash(Sha256Hasher::new().digest(data))
+    }
+
+    /// Five leaves of 100, 200, ..., 500 bytes, byte `i` of leaf `n` is `n * 7 + i`.
+    fn dag_of_five(fanout: usize) -> UnixFsDag {
+        let mut dag = UnixFsDag::new().with_fanout(fanout);
+        for n in 0..5u64 {
+            let data: Vec<u8> = (0..(n + 1) * 100).map(|i| (n * 7 + i) as u8).collect();
+            dag.push_chunk(&chunk_of(&data)).unwrap();
+        }
+        dag
+    }
+
+    #[test]
+    fn test_empty_root_is_empty_raw_block() {
+        let mut dag = UnixFsDag::new();
+        assert_eq!(
+            dag.root().to_string(),
+            "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"
+        );
+        assert!(dag.nodes().is_empty());
+    }
+
+    #[test]
+    fn test_single_chunk_root_is_leaf() {
+        let mut dag = UnixFsDag::new();
+        dag.push_chunk(&chunk_of(b"hello world")).unwrap();
+        assert_eq!(
+            dag.root().to_string(),
+            "bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e"
+        );
+    }
+
+    #[test]
+    fn test_empty_file_node_known_cid() {
+        // The canonical empty UnixFS file, `QmbFMke1KXqnYyBBWxB74N4c5SBnJMVAiMNRcGu6x1AwQH`.
+        let node = encode_node(&[]);
+        assert_eq!(node, [0x0a, 0x04, 0x08, 0x02, 0x18, 0x00]);
+        let hash = Sha256Hasher::new().digest(&node);
+        assert_eq!(
+            Cid::new(Cid::DAG_PB, ChunkId::from(hash)).to_string(),
+            "bafybeif7ztnhq65lumvvtr4ekcwd2ifwgm3awq4zfr3srh462rwyinlb4y"
+        );
+    }
+
+    #[test]
+    fn test_single_level_known_cid() {
+        let mut dag = dag_of_five(UnixFsDag::DEFAULT_FANOUT);
+        assert_eq!(dag.root().to_string(), ROOT_FLAT);
+        assert_eq!(dag.nodes().len(), 1);
+    }
+
+    #[test]
+    fn test_balanced_layout_known_cids() {
+        // fanout 2: ((l0 l1) (l2 l3)) (l4)
+        let mut dag = dag_of_five(2);
+        assert_eq!(dag.root().to_string(), ROOT_FANOUT_2);
+        assert_eq!(dag.nodes().len(), 3 + 2 + 1);
+
+        // fanout 3: (l0 l1 l2) (l3 l4)
+        let mut dag = dag_of_five(3);
+        assert_eq!(dag.root().to_string(), ROOT_FANOUT_3);
+        assert_eq!(dag.nodes().len(), 2 + 1);
+    }
+
+    #[test]
+    fn test_nodes_are_content_addressed() {
+        let mut dag = dag_of_five(2);
+        let root = dag.root();
+        let nodes = dag.nodes();
+        assert_eq!(nodes.last().unwrap().0, root);
+        for (cid, bytes) in nodes {
+            let hash = Sha256Hasher::new().digest(&bytes);
+            assert_eq!(cid, Cid::new(Cid::DAG_PB, ChunkId::from(hash)));
+        }
+    }
+
+    #[test]
+    fn test_push_chunk_validates_input() {
+        let mut dag = UnixFsDag::new();
+        assert!(dag.push_chunk(&Chunk::new(&b"abc"[..])).is_err());
+        assert!(dag.push_chunk(&chunk_of(b"abc").set_offset(3)).is_err());
+        dag.push_chunk(&chunk_of(b"abc").set_offset(0)).unwrap();
+        assert_eq!(dag.stream_len(), 3);
+    }
+
+    // Computed with an independent dag-pb/UnixFS encoder.
+    const ROOT_FLAT: &str = "bafybeib3s2jnwinzi45z2myq2vgnv5nzyqfl2juosn5ff2q3vi3sb3ir6u";
+    const ROOT_FANOUT_2: &str = "bafybeihbvy6wllmowbhq6mc644n5bqempmcgdt3hcozsorrdjjxdcwg5ou";
+    const ROOT_FANOUT_3: &str = "bafybeigzormhzfn57ywualpbrgto3poxs76hlgbfsp6ptpf4pk4hdbhrla";
+}
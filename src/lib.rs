@@ -24,18 +24,32 @@
 //!
 //! ## Features
 //!
-//! - `hash-blake3` (default) - BLAKE3 cryptographic hashing
+//! - `hash-blake3` (default) - BLAKE3 cryptographic hashing, optionally keyed
+//!   via [`HashConfig::with_key`]
+//! - `hash-sha256` - SHA-256 hashing for OCI/IPFS interoperability
+//! - `hash-xxh3` - XXH3-128 fast non-cryptographic hashing for trusted data
+//! - `futures` - Runtime-agnostic chunk `Stream` over `futures::io::AsyncRead`
+//! - `tokio` - `ChunkDecoder`, a `tokio_util::codec::Decoder` for `FramedRead`
+//! - `mmap` - Zero-copy chunking of `memmap2` mappings
+//! - `serde` - `Serialize`/`Deserialize` for configurations, hashes and chunk
+//!   metadata (keys are never serialized)
+//! - `bao` - BLAKE3/Bao outboard trees for verified streaming of chunk slices
+//! - `ipfs` - CIDv1 per chunk and balanced UnixFS file DAGs
+//!
+//! Other algorithms can be plugged in by implementing [`ChunkHasher`] and
+//! passing a factory to [`HashConfig::with_hasher`].
 //!
 //! # Quick Start
 //!
 //! ```
-//! use chunkrs::{Chunker, ChunkConfig};
+//! use chunkrs::{ChunkConfig, ChunkSession};
 //! use bytes::Bytes;
 //!
-//! let mut chunker = Chunker::new(ChunkConfig::default());
-//! let (chunks, _pending) = chunker.push(Bytes::from("hello world"));
+//! let mut session = ChunkSession::new(ChunkConfig::default());
+//! let chunks = session.push(Bytes::from("hello world"));
 //!
-//! if let Some(final_chunk) = chunker.finish() {
+//! let (final_chunk, _summary) = session.finish();
+//! if let Some(final_chunk) = final_chunk {
 //!     println!("Final chunk: {} bytes", final_chunk.len());
 //! }
 //!
@@ -49,17 +63,35 @@
 #![warn(missing_docs)]
 
 // Internal modules
+#[cfg(feature = "bao")]
+mod bao;
 mod cdc;
 mod chunk;
 mod chunker;
 mod config;
 mod error;
-#[cfg(feature = "hash-blake3")]
 mod hash;
+mod io;
+#[cfg(feature = "ipfs")]
+mod ipfs;
+mod merkle;
 mod util;
 
 // Public API (flat design)
-pub use chunk::{Chunk, ChunkHash};
-pub use chunker::Chunker;
+#[cfg(feature = "bao")]
+pub use bao::BaoOutboard;
+pub use chunk::{Chunk, ChunkHash, ChunkId, ChunkMeta};
+pub use chunker::{Checkpoint, ChunkSession, Chunker, MetaChunker, StreamSummary};
 pub use config::{ChunkConfig, HashConfig};
 pub use error::ChunkError;
+pub use hash::{ChunkHasher, HashAlgorithm, HasherFactory, RollingChecksum};
+#[cfg(feature = "tokio")]
+pub use io::ChunkDecoder;
+#[cfg(feature = "futures")]
+pub use io::ChunkStream;
+#[cfg(feature = "mmap")]
+pub use io::MmapChunks;
+pub use io::{ChunkReader, ChunkSink, ChunkWriter};
+#[cfg(feature = "ipfs")]
+pub use ipfs::{Cid, UnixFsDag};
+pub use merkle::{MerkleProof, MerkleTree};
@@ -0,0 +1,43 @@
+//! Merkle trees over chunk hashes.
+//!
+//! - [`MerkleTree`] - Incremental tree over the ordered chunks of a stream
+//! - [`MerkleProof`] - Compact inclusion proof for a single chunk
+
+mod proof;
+mod tree;
+
+// Re-export for use within the crate (module is private, so pub is crate-local)
+pub use proof::MerkleProof;
+pub use tree::MerkleTree;
+
+use crate::chunk::ChunkHash;
+use crate::hash::ChunkHasher;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Hashes a leaf entry.
+fn hash_leaf(hasher: &mut dyn ChunkHasher, offset: u64, len: u64, hash: &ChunkHash) -> ChunkHash {
+    hasher.reset();
+    hasher.update(&[LEAF_PREFIX]);
+    hasher.update(&offset.to_le_bytes());
+    hasher.update(&len.to_le_bytes());
+    hasher.update(&hash.algorithm().multihash_code().to_le_bytes());
+    hasher.update(hash.digest());
+    hasher.finalize()
+}
+
+/// Hashes an interior node.
+fn hash_node(hasher: &mut dyn ChunkHasher, left: &ChunkHash, right: &ChunkHash) -> ChunkHash {
+    hasher.reset();
+    hasher.update(&[NODE_PREFIX]);
+    hasher.update(left.digest());
+    hasher.update(right.digest());
+    hasher.finalize()
+}
+
+/// Returns the largest power of two strictly smaller than `n` (`n > 1`).
+fn split_point(n: usize) -> usize {
+    debug_assert!(n > 1);
+    1 << (usize::BITS - 1 - (n - 1).leading_zeros())
+}
@@ -0,0 +1,146 @@
+//! Merkle inclusion proofs.
+
+use super::{hash_leaf, hash_node};
+use crate::chunk::ChunkHash;
+use crate::config::HashConfig;
+use crate::hash::ChunkHasher;
+
+/// Inclusion proof for one chunk of a [`MerkleTree`](crate::MerkleTree).
+///
+/// A proof authenticates the chunk's hash together with its `offset` and
+/// `len` in the stream. It holds `O(log n)` sibling hashes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// Index of the chunk in the stream.
+    pub index: u64,
+
+    /// Number of chunks in the tree the proof was created for.
+    pub leaf_count: u64,
+
+    /// Offset of the chunk in the stream.
+    pub offset: u64,
+
+    /// Length of the chunk in bytes.
+    pub len: u64,
+
+    /// Sibling hashes from the leaf level up to the root.
+    pub siblings: Vec<ChunkHash>,
+}
+
+impl MerkleProof {
+    /// Verifies that `chunk_hash` is the chunk at this proof's index, offset
+    /// and length in the tree with the given `root`.
+    ///
+    /// The node hasher is the built-in hasher for `root`'s algorithm; returns
+    /// `false` if it is unavailable (for keyed or custom algorithms use
+    /// [`MerkleProof::verify_with`]).
+    pub fn verify(&self, chunk_hash: &ChunkHash, root: &ChunkHash) -> bool {
+        match HashConfig::enabled()
+            .with_algorithm(root.algorithm())
+            .build_hasher()
+        {
+            Some(mut hasher) => self.verify_with(hasher.as_mut(), chunk_hash, root),
+            None => false,
+        }
+    }
+
+    /// Verifies the proof using `hasher` for tree nodes.
+    pub fn verify_with(
+        &self,
+        hasher: &mut dyn ChunkHasher,
+        chunk_hash: &ChunkHash,
+        root: &ChunkHash,
+    ) -> bool {
+        if self.index >= self.leaf_count {
+            return false;
+        }
+
+        // RFC 9162, section 2.1.3.2.
+        let mut fn_ = self.index;
+        let mut sn = self.leaf_count - 1;
+        let mut r = hash_leaf(hasher, self.offset, self.len, chunk_hash);
+
+        for sibling in &self.siblings {
+            if sn == 0 {
+                return false;
+            }
+            if fn_ & 1 == 1 || fn_ == sn {
+                r = hash_node(hasher, sibling, &r);
+                while fn_ & 1 == 0 && fn_ != 0 {
+                    fn_ >>= 1;
+                    sn >>= 1;
+                }
+            } else {
+                r = hash_node(hasher, &r, sibling);
+            }
+            fn_ >>= 1;
+            sn >>= 1;
+        }
+
+        sn == 0 && r == *root
+    }
+}
+
+#[cfg(all(test, feature = "hash-blake3"))]
+mod tests {
+    use crate::MerkleTree;
+    use crate::hash::Blake3Hasher;
+
+    fn tree_of(n: usize) -> MerkleTree {
+        let mut tree = MerkleTree::new();
+        for i in 0..n {
+            tree.push_hash(100, Blake3Hasher::hash(&[i as u8]));
+        }
+        tree
+    }
+
+    #[test]
+    fn test_proof_rejects_wrong_chunk() {
+        let tree = tree_of(5);
+        let root = tree.root();
+        let proof = tree.proof(3).unwrap();
+
+        assert!(proof.verify(&tree.leaf(3).unwrap(), &root));
+        assert!(!proof.verify(&tree.leaf(2).unwrap(), &root));
+    }
+
+    #[test]
+    fn test_proof_binds_offset_and_len() {
+        let tree = tree_of(5);
+        let root = tree.root();
+        let chunk = tree.leaf(3).unwrap();
+        let proof = tree.proof(3).unwrap();
+        assert_eq!(proof.offset, 300);
+
+        let mut moved = proof.clone();
+        moved.offset = 0;
+        assert!(!moved.verify(&chunk, &root));
+
+        let mut resized = proof.clone();
+        resized.len = 99;
+        assert!(!resized.verify(&chunk, &root));
+    }
+
+    #[test]
+    fn test_proof_rejects_tampering() {
+        let tree = tree_of(6);
+        let root = tree.root();
+        let chunk = tree.leaf(4).unwrap();
+        let proof = tree.proof(4).unwrap();
+
+        let mut wrong_index = proof.clone();
+        wrong_index.index = 5;
+        assert!(!wrong_index.verify(&chunk, &root));
+
+        let mut truncated = proof.clone();
+        truncated.siblings.pop();
+        assert!(!truncated.verify(&chunk, &root));
+
+        let mut out_of_range = proof.clone();
+        out_of_range.index = 6;
+        assert!(!out_of_range.verify(&chunk, &root));
+
+        let other = tree_of(7);
+        assert!(!proof.verify(&chunk, &other.root()));
+    }
+}
@@ -0,0 +1,330 @@
+//! Incremental Merkle tree construction.
+
+use super::{MerkleProof, hash_leaf, hash_node, split_point};
+use crate::chunk::{Chunk, ChunkHash};
+use crate::error::ChunkError;
+use crate::hash::{ChunkHasher, HashAlgorithm};
+
+/// A Merkle tree over the ordered chunk hashes of one stream.
+///
+/// Chunks are added as they leave [`Chunker::push_buf`](crate::Chunker::push_buf),
+/// in stream order. Inclusion proofs are produced by [`MerkleTree::proof`].
+///
+/// # Hashing Scheme
+///
+/// The tree shape follows RFC 6962 (Certificate Transparency): for `n > 1`
+/// leaves the left subtree holds the largest power of two smaller than `n`.
+/// Leaves and interior nodes are domain-separated by a one-byte prefix:
+///
+/// ```text
+/// leaf  = H(0x00 || offset:u64le || len:u64le || code:u64le || chunk digest)
+/// node  = H(0x01 || left digest || right digest)
+/// empty = H("")
+/// ```
+///
+/// `code` is the chunk hash's [`HashAlgorithm::multihash_code`], so a leaf
+/// commits to the chunk's position, length and identity. The empty root is
+/// RFC 6962's `MTH({}) = HASH()` unchanged; it needs no prefix of its own,
+/// since every leaf and node input is at least one byte long. `H` is the tree's
+/// [`ChunkHasher`] (BLAKE3 by default).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "hash-blake3")]
+/// # {
+/// use chunkrs::{ChunkConfig, Chunker, MerkleTree};
+/// use bytes::Bytes;
+///
+/// let mut chunker = Chunker::new(ChunkConfig::new(4, 16, 64)?);
+/// let mut tree = MerkleTree::new();
+///
+/// let chunks = chunker.push_buf(Bytes::from(vec![7u8; 500]));
+/// for chunk in chunks.iter().chain(chunker.finish().iter()) {
+///     tree.push_chunk(chunk)?;
+/// }
+///
+/// let root = tree.root();
+/// let proof = tree.proof(2).unwrap();
+/// assert!(proof.verify(&tree.leaf(2).unwrap(), &root));
+/// # }
+/// # Ok::<(), chunkrs::ChunkError>(())
+/// ```
+#[derive(Debug)]
+pub struct MerkleTree {
+    hasher: Box<dyn ChunkHasher>,
+    /// `(chunk hash, offset, len)` per chunk.
+    leaves: Vec<(ChunkHash, u64, u64)>,
+    /// Roots of the completed perfect subtrees by height: `nodes[h][i]`
+    /// covers leaves `i * 2^h .. (i + 1) * 2^h`, so `nodes[0]` holds the leaf
+    /// hashes.
+    nodes: Vec<Vec<ChunkHash>>,
+    len: u64,
+}
+
+impl MerkleTree {
+    /// Creates an empty tree hashed with BLAKE3 (requires `hash-blake3` feature).
+    #[cfg(feature = "hash-blake3")]
+    pub fn new() -> Self {
+        Self::with_hasher(Box::new(crate::hash::Blake3Hasher::new()))
+    }
+
+    /// Creates an empty tree whose nodes are hashed with `hasher`.
+    pub fn with_hasher(hasher: Box<dyn ChunkHasher>) -> Self {
+        Self {
+            hasher,
+            leaves: Vec::new(),
+            nodes: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the algorithm used for tree nodes.
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.hasher.algorithm()
+    }
+
+    /// Appends the next chunk of the stream.
+    ///
+    /// Returns an error if the chunk has no hash, or if its offset is set and
+    /// does not equal the total length of the chunks added so far.
+    pub fn push_chunk(&mut self, chunk: &Chunk) -> Result<(), ChunkError> {
+        let hash = chunk.hash.ok_or(ChunkError::InvalidInput {
+            message: "chunk has no hash",
+        })?;
+        if chunk.offset.is_some_and(|offset| offset != self.len) {
+            return Err(ChunkError::InvalidInput {
+                message: "chunk offset does not continue the stream",
+            });
+        }
+        self.push_hash(chunk.len() as u64, hash);
+        Ok(())
+    }
+
+    /// Appends the hash of the next chunk, `len` bytes long.
+    ///
+    /// The chunk's offset is the total length of the chunks added so far.
+    /// Every subtree the new leaf completes is hashed now, which costs one
+    /// node hash per leaf on average.
+    pub fn push_hash(&mut self, len: u64, hash: ChunkHash) {
+        let mut node = hash_leaf(self.hasher.as_mut(), self.len, len, &hash);
+        self.leaves.push((hash, self.len, len));
+        self.len += len;
+
+        for height in 0.. {
+            if self.nodes.len() == height {
+                self.nodes.push(Vec::new());
+            }
+            let level = &mut self.nodes[height];
+            level.push(node);
+            if level.len() % 2 == 1 {
+                break;
+            }
+            let (left, right) = (level[level.len() - 2], level[level.len() - 1]);
+            node = hash_node(self.hasher.as_mut(), &left, &right);
+        }
+    }
+
+    /// Returns the number of leaves (chunks).
+    pub fn leaf_count(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns `true` if no chunks have been added.
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Returns the total length of the chunks added so far.
+    pub fn stream_len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns the chunk hash stored at `index`.
+    pub fn leaf(&self, index: usize) -> Option<ChunkHash> {
+        self.leaves.get(index).map(|(hash, _, _)| *hash)
+    }
+
+    /// Computes the root hash.
+    ///
+    /// Only the incomplete subtrees along the right edge are hashed, at most
+    /// `log2(n)` nodes. The root of an empty tree is the hash of the empty
+    /// string, as in RFC 6962.
+    pub fn root(&self) -> ChunkHash {
+        let mut hasher = self.hasher.fork();
+        if self.leaves.is_empty() {
+            return hasher.digest(&[]);
+        }
+        self.range_root(hasher.as_mut(), 0, self.leaves.len())
+    }
+
+    /// Creates an inclusion proof for the chunk at `index`.
+    ///
+    /// Siblings that are completed subtrees are looked up; only those on the
+    /// right edge are hashed. Returns `None` if `index` is out of range.
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        let &(_, offset, len) = self.leaves.get(index)?;
+        let mut hasher = self.hasher.fork();
+        let mut siblings = Vec::new();
+        self.audit_path(hasher.as_mut(), index, 0, self.leaves.len(), &mut siblings);
+        Some(MerkleProof {
+            index: index as u64,
+            leaf_count: self.leaves.len() as u64,
+            offset,
+            len,
+            siblings,
+        })
+    }
+
+    /// Computes the root of the `size` leaves starting at `start`.
+    ///
+    /// `start` is a multiple of the largest power of two not above `size`,
+    /// which holds for every subtree of an RFC 6962 tree.
+    fn range_root(&self, hasher: &mut dyn ChunkHasher, start: usize, size: usize) -> ChunkHash {
+        if size.is_power_of_two() {
+            let height = size.trailing_zeros() as usize;
+            return self.nodes[height][start >> height];
+        }
+        let k = split_point(size);
+        let left = self.range_root(hasher, start, k);
+        let right = self.range_root(hasher, start + k, size - k);
+        hash_node(hasher, &left, &right)
+    }
+
+    /// Collects sibling hashes from the leaf up to the root (RFC 6962 `PATH`)
+    /// for leaf `index` of the `size` leaves starting at `start`.
+    fn audit_path(
+        &self,
+        hasher: &mut dyn ChunkHasher,
+        index: usize,
+        start: usize,
+        size: usize,
+        out: &mut Vec<ChunkHash>,
+    ) {
+        if size <= 1 {
+            return;
+        }
+        let k = split_point(size);
+        if index < k {
+            self.audit_path(hasher, index, start, k, out);
+            out.push(self.range_root(hasher, start + k, size - k));
+        } else {
+            self.audit_path(hasher, index - k, start + k, size - k, out);
+            out.push(self.range_root(hasher, start, k));
+        }
+    }
+}
+
+#[cfg(feature = "hash-blake3")]
+impl Default for MerkleTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "hash-blake3"))]
+mod tests {
+    use super::*;
+    use crate::hash::Blake3Hasher;
+
+    fn tree_of(n: usize) -> MerkleTree {
+        let mut tree = MerkleTree::new();
+        for i in 0..n {
+            let len = 10 + i as u64;
+            tree.push_hash(len, Blake3Hasher::hash(&[i as u8]));
+        }
+        tree
+    }
+
+    /// Recomputes a root from the leaf hashes, as RFC 6962 defines it.
+    fn naive_root(hasher: &mut Blake3Hasher, leaves: &[ChunkHash]) -> ChunkHash {
+        if leaves.len() == 1 {
+            return leaves[0];
+        }
+        let k = split_point(leaves.len());
+        let left = naive_root(hasher, &leaves[..k]);
+        let right = naive_root(hasher, &leaves[k..]);
+        hash_node(hasher, &left, &right)
+    }
+
+    #[test]
+    fn test_empty_root() {
+        let tree = MerkleTree::new();
+        assert_eq!(tree.root(), Blake3Hasher::hash(b""));
+        assert!(tree.proof(0).is_none());
+    }
+
+    #[test]
+    fn test_empty_root_is_pinned() {
+        // BLAKE3("") - changing it breaks every stored empty-stream root.
+        assert_eq!(
+            MerkleTree::new().root().to_hex(),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+    }
+
+    #[test]
+    fn test_single_leaf_root_is_leaf_hash() {
+        let tree = tree_of(1);
+        let chunk = tree.leaf(0).unwrap();
+        let mut hasher = Blake3Hasher::new();
+        assert_eq!(tree.root(), hash_leaf(&mut hasher, 0, 10, &chunk));
+    }
+
+    #[test]
+    fn test_three_leaf_shape() {
+        // RFC 6962: root = node(node(l0, l1), l2)
+        let tree = tree_of(3);
+        let mut h = Blake3Hasher::new();
+        let l = &tree.nodes[0];
+        let left = hash_node(&mut h, &l[0], &l[1]);
+        assert_eq!(tree.root(), hash_node(&mut h, &left, &l[2]));
+    }
+
+    #[test]
+    fn test_incremental_root_matches_full_rebuild() {
+        let mut tree = MerkleTree::new();
+        let mut hasher = Blake3Hasher::new();
+        for n in 1..=40u64 {
+            tree.push_hash(n, Blake3Hasher::hash(&n.to_le_bytes()));
+            assert_eq!(
+                tree.root(),
+                naive_root(&mut hasher, &tree.nodes[0]),
+                "{} leaves",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_proofs_verify_for_all_sizes() {
+        for n in 1..=17 {
+            let tree = tree_of(n);
+            let root = tree.root();
+            for i in 0..n {
+                let proof = tree.proof(i).unwrap();
+                assert!(
+                    proof.verify(&tree.leaf(i).unwrap(), &root),
+                    "proof {} of {} must verify",
+                    i,
+                    n
+                );
+                assert!(proof.siblings.len() <= usize::BITS as usize - n.leading_zeros() as usize);
+            }
+        }
+    }
+
+    #[test]
+    fn test_push_chunk_validates_input() {
+        let mut tree = MerkleTree::new();
+        assert!(tree.push_chunk(&Chunk::new(&b"abc"[..])).is_err());
+
+        let hash = Blake3Hasher::hash(b"abc");
+        let misplaced = Chunk::with_offset(&b"abc"[..], 5).set_hash(hash);
+        assert!(tree.push_chunk(&misplaced).is_err());
+
+        let first = Chunk::with_offset(&b"abc"[..], 0).set_hash(hash);
+        tree.push_chunk(&first).unwrap();
+        assert_eq!(tree.stream_len(), 3);
+    }
+}
@@ -3,15 +3,20 @@
 // Test categories:
 // - Basic Functionality: empty input, small data, large data
 // - Streaming and Push/FINISH Semantics: batch processing, pending bytes
-// - Offset Tracking: position tracking, reset behavior
+// - Offset Tracking: position tracking, reset behavior, base offsets
 // - Size Constraints: min/max size enforcement
 // - Determinism: same input → same output regardless of batching
 // - Zero-Copy Verification: memory efficiency validation
 // - Hashing Tests: hash generation and consistency
 // - Edge Cases: validation, error conditions, data integrity
+//
+// Most tests drive the deprecated `Chunker::push()` on purpose to keep its
+// behaviour covered; `ChunkSession` has its own section.
+
+#![allow(deprecated)]
 
 use bytes::Bytes;
-use chunkrs::{ChunkConfig, Chunker, HashConfig};
+use chunkrs::{ChunkConfig, ChunkSession, Chunker, HashConfig, MetaChunker};
 
 // ============================================================================
 // Basic Functionality Tests
@@ -203,6 +208,7 @@ fn test_chunk_offset_tracking() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_first_then_check)]
 fn test_offset_resets_after_reset() {
     let mut chunker = Chunker::new(ChunkConfig::new(4, 16, 64).unwrap());
 
@@ -264,6 +270,32 @@ fn test_exact_max_size_boundary() {
     );
 }
 
+#[test]
+fn test_non_power_of_two_sizes() {
+    // 12-bit masks (4096) switching at 3000 instead of 4096: the mean (about
+    // 4000 here) lands below that of a 4096 average (about 4900).
+    let config = ChunkConfig::new(750, 3000, 12000).unwrap();
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let data: Vec<u8> = (0..1_000_000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect();
+
+    let mut session = ChunkSession::new(config);
+    let mut chunks = session.push(Bytes::from(data));
+    let (final_chunk, summary) = session.finish();
+    let last = final_chunk.or_else(|| chunks.pop()).unwrap();
+
+    assert!(chunks.iter().all(|c| (750..=12000).contains(&c.len())));
+    assert!(last.len() <= 12000);
+    let mean = summary.len / summary.chunk_count;
+    assert!((3000..4600).contains(&mean), "mean chunk size {}", mean);
+}
+
 // ============================================================================
 // Determinism
 // ============================================================================
@@ -306,6 +338,7 @@ fn test_determinism_across_push_sizes() {
     );
 }
 
+#[cfg(feature = "hash-blake3")]
 #[test]
 fn test_same_stream_same_chunks_same_hashes() {
     let data: Vec<u8> = (0..800).map(|i| (i % 256) as u8).collect();
@@ -474,6 +507,896 @@ mod hashing_tests {
             }
         }
     }
+
+    #[test]
+    fn test_try_new_rejects_config_without_hasher() {
+        let keyless = HashConfig::enabled().with_algorithm(chunkrs::HashAlgorithm::Blake3Keyed);
+        let config = ChunkConfig::default().with_hash_config(keyless);
+        assert!(matches!(
+            Chunker::try_new(config),
+            Err(chunkrs::ChunkError::InvalidConfig { .. })
+        ));
+
+        let keyed = config.with_hash_config(keyless.with_key([9; 32]));
+        let mut chunker = Chunker::try_new(keyed).unwrap();
+        chunker.push_buf(Bytes::from(&b"data"[..]));
+        assert!(chunker.finish().unwrap().hash.is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "keyed BLAKE3 requires a key")]
+    fn test_new_panics_on_config_without_hasher() {
+        let keyless = HashConfig::enabled().with_algorithm(chunkrs::HashAlgorithm::Blake3Keyed);
+        Chunker::new(ChunkConfig::default().with_hash_config(keyless));
+    }
+}
+
+// ============================================================================
+// Stream Summary
+// ============================================================================
+
+#[test]
+fn test_summary_counts_len_and_chunks() {
+    let mut chunker = Chunker::new(ChunkConfig::new(4, 16, 64).unwrap());
+    let data: Vec<u8> = (0..1000).map(|i| (i % 256) as u8).collect();
+
+    let (chunks, _) = chunker.push(Bytes::from(data));
+    let (final_chunk, summary) = chunker.finish_with_summary();
+
+    let expected_count = chunks.len() + usize::from(final_chunk.is_some());
+    assert_eq!(summary.len, 1000);
+    assert_eq!(summary.chunk_count, expected_count as u64);
+    assert!(summary.digest.is_none(), "Stream digest is opt-in");
+    assert_eq!(chunker.offset(), 0, "Chunker must be reset after summary");
+}
+
+#[cfg(feature = "hash-blake3")]
+mod stream_digest_tests {
+    use super::*;
+
+    fn config() -> ChunkConfig {
+        ChunkConfig::new(4, 16, 64)
+            .unwrap()
+            .with_hash_config(HashConfig::enabled().with_stream_digest(true))
+    }
+
+    fn digest_of(data: &[u8], piece_len: usize) -> chunkrs::StreamSummary {
+        let mut chunker = Chunker::new(config());
+        for piece in data.chunks(piece_len) {
+            chunker.push(Bytes::copy_from_slice(piece));
+        }
+        chunker.finish_with_summary().1
+    }
+
+    #[test]
+    fn test_stream_digest_matches_single_chunk_hash() {
+        // Below min_size the whole stream is one chunk, so both hashes agree.
+        let mut chunker = Chunker::new(config());
+        chunker.push(Bytes::from(&b"ab"[..]));
+        let (final_chunk, summary) = chunker.finish_with_summary();
+
+        assert_eq!(summary.digest, final_chunk.unwrap().hash);
+        assert_eq!(summary.chunk_count, 1);
+    }
+
+    #[test]
+    fn test_stream_digest_independent_of_push_sizes() {
+        let data: Vec<u8> = (0..5000).map(|i| (i * 7 % 256) as u8).collect();
+
+        let whole = digest_of(&data, data.len());
+        assert!(whole.digest.is_some());
+        for piece_len in [1, 10, 37, 4096] {
+            assert_eq!(
+                digest_of(&data, piece_len),
+                whole,
+                "Summary must not depend on push size {}",
+                piece_len
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_digest_resets_between_streams() {
+        let mut chunker = Chunker::new(config());
+        chunker.push(Bytes::from(&b"first stream"[..]));
+        let (_, first) = chunker.finish_with_summary();
+
+        chunker.push(Bytes::from(&b"first stream"[..]));
+        let (_, second) = chunker.finish_with_summary();
+
+        assert_eq!(first, second, "Each stream must start from a fresh state");
+    }
+
+    #[test]
+    fn test_stream_digest_without_chunk_hashes() {
+        let data: Vec<u8> = (0..5000).map(|i| (i * 7 % 256) as u8).collect();
+        let config = ChunkConfig::new(4, 16, 64)
+            .unwrap()
+            .with_hash_config(HashConfig::disabled().with_stream_digest(true));
+
+        let mut session = ChunkSession::new(config);
+        let mut chunks = session.push(Bytes::from(data.clone()));
+        let (final_chunk, summary) = session.finish();
+        chunks.extend(final_chunk);
+
+        assert!(chunks.iter().all(|c| c.hash.is_none()));
+        assert_eq!(
+            summary.digest,
+            Some(chunkrs::ChunkHash::new(*blake3::hash(&data).as_bytes()))
+        );
+        assert_eq!(summary.digest, digest_of(&data, 100).digest);
+    }
+}
+
+#[cfg(feature = "hash-sha256")]
+mod sha256_tests {
+    use super::*;
+    use chunkrs::{ChunkHash, HashAlgorithm};
+
+    #[test]
+    fn test_sha256_known_answer() {
+        let config = ChunkConfig::default()
+            .with_hash_config(HashConfig::enabled().with_algorithm(HashAlgorithm::Sha256));
+        let mut chunker = Chunker::new(config);
+
+        let (chunks, _) = chunker.push(Bytes::from(&b"abc"[..]));
+        assert!(chunks.is_empty());
+
+        let hash = chunker.finish().unwrap().hash.unwrap();
+        assert_eq!(hash.algorithm(), HashAlgorithm::Sha256);
+        assert_eq!(
+            hash.to_hex(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            ChunkHash::from_hex_with_algorithm(HashAlgorithm::Sha256, &hash.to_hex()),
+            Some(hash)
+        );
+    }
+
+    #[test]
+    fn test_sha256_hashes_across_push_sizes() {
+        let data: Vec<u8> = (0..800).map(|i| (i % 251) as u8).collect();
+        let config = ChunkConfig::new(4, 16, 64)
+            .unwrap()
+            .with_hash_config(HashConfig::enabled().with_algorithm(HashAlgorithm::Sha256));
+
+        let mut chunker1 = Chunker::new(config);
+        let (mut all1, _) = chunker1.push(Bytes::from(data.clone()));
+        all1.extend(chunker1.finish());
+
+        let mut chunker2 = Chunker::new(config);
+        let mut all2 = Vec::new();
+        for piece in data.chunks(13) {
+            let (chunks, _) = chunker2.push(Bytes::copy_from_slice(piece));
+            all2.extend(chunks);
+        }
+        all2.extend(chunker2.finish());
+
+        let hashes1: Vec<_> = all1.iter().map(|c| c.hash).collect();
+        let hashes2: Vec<_> = all2.iter().map(|c| c.hash).collect();
+        assert_eq!(
+            hashes1, hashes2,
+            "SHA-256 hashes must not depend on batching"
+        );
+        assert!(
+            hashes1
+                .iter()
+                .all(|h| h.unwrap().algorithm() == HashAlgorithm::Sha256)
+        );
+    }
+}
+
+// ============================================================================
+// Weak Checksum
+// ============================================================================
+
+#[test]
+fn test_weak_checksum_finds_chunks_in_shifted_stream() {
+    use chunkrs::RollingChecksum;
+
+    let config = ChunkConfig::new(16, 64, 256)
+        .unwrap()
+        .with_hash_config(HashConfig::disabled().with_weak_checksum(true));
+    let mut chunker = Chunker::new(config);
+    let data: Vec<u8> = (0..2000u32).map(|i| (i * 7 % 251) as u8).collect();
+
+    let (chunks, _) = chunker.push(Bytes::from(data.clone()));
+    let target = &chunks[1];
+    let weak = target.weak_hash.expect("weak checksum enabled");
+    assert!(target.hash.is_none(), "strong hash stays disabled");
+
+    // Insert 3 bytes at the front; the chunk must be found 3 bytes later.
+    let mut shifted = vec![0xAA; 3];
+    shifted.extend_from_slice(&data);
+    let mut rolling = RollingChecksum::new(target.len());
+    let found = shifted
+        .iter()
+        .enumerate()
+        .filter(|&(_, &byte)| rolling.push(byte) == Some(weak))
+        .map(|(i, _)| (i + 1 - target.len()) as u64)
+        .any(|start| start == target.start() + 3);
+    assert!(found, "rolling checksum must locate the shifted chunk");
+}
+
+#[test]
+fn test_weak_checksum_is_opt_in() {
+    let mut chunker = Chunker::new(ChunkConfig::new(4, 16, 64).unwrap());
+    let (chunks, _) = chunker.push(Bytes::from(vec![1u8; 500]));
+    assert!(chunks.iter().all(|c| c.weak_hash.is_none()));
+}
+
+// ============================================================================
+// Checkpoint and Restore
+// ============================================================================
+
+mod checkpoint_tests {
+    use super::*;
+    use chunkrs::{Checkpoint, Chunk};
+
+    fn config() -> ChunkConfig {
+        ChunkConfig::new(64, 256, 1024).unwrap()
+    }
+
+    fn data() -> Vec<u8> {
+        (0..6000u32).map(|i| (i * 131 % 251) as u8).collect()
+    }
+
+    fn summarize(chunks: &[Chunk]) -> Vec<(Option<u64>, Bytes, Option<chunkrs::ChunkHash>)> {
+        chunks
+            .iter()
+            .map(|c| (c.offset, c.data.clone(), c.hash))
+            .collect()
+    }
+
+    #[test]
+    fn test_resumed_stream_matches_uninterrupted_run() {
+        let data = data();
+        let mut chunker = Chunker::new(config());
+        let (chunks, _) = chunker.push(Bytes::from(data.clone()));
+        let expected: Vec<Chunk> = chunks.into_iter().chain(chunker.finish()).collect();
+
+        for split in [1, 15, 16, 100, 2999, 5999] {
+            let mut chunker = Chunker::new(config());
+            let (mut chunks, _) = chunker.push(Bytes::copy_from_slice(&data[..split]));
+            let saved = chunker.checkpoint().to_bytes();
+            drop(chunker);
+
+            let checkpoint = Checkpoint::from_bytes(&saved).unwrap();
+            let start = checkpoint.offset as usize;
+            let resume = start + checkpoint.pending_len as usize;
+            assert_eq!(resume, split);
+
+            let pending = Bytes::copy_from_slice(&data[start..resume]);
+            let mut chunker = Chunker::restore(config(), &checkpoint, pending).unwrap();
+            chunks.extend(chunker.push(Bytes::copy_from_slice(&data[resume..])).0);
+            let (final_chunk, summary) = chunker.finish_with_summary();
+            chunks.extend(final_chunk);
+
+            assert_eq!(summarize(&chunks), summarize(&expected), "split {}", split);
+            assert_eq!(summary.chunk_count, expected.len() as u64);
+        }
+    }
+
+    #[test]
+    fn test_restore_in_meta_mode() {
+        let data = data();
+        let mut chunker = MetaChunker::new(config());
+        let mut expected = chunker.push(&data);
+        expected.extend(chunker.finish());
+
+        let mut chunker = MetaChunker::new(config());
+        let mut metas = chunker.push(&data[..1234]);
+        let checkpoint = chunker.checkpoint();
+        let start = checkpoint.offset as usize;
+
+        let pending = &data[start..1234];
+        let mut chunker = MetaChunker::restore(config(), &checkpoint, pending).unwrap();
+        metas.extend(chunker.push(&data[1234..]));
+        metas.extend(chunker.finish());
+        assert_eq!(metas, expected);
+    }
+
+    /// Checkpoints a chunker once 128 bytes are pending, enough for restore to
+    /// check them, and returns the pending bytes.
+    fn checkpoint_after_data() -> (Checkpoint, Bytes) {
+        let data = data();
+        let mut chunker = Chunker::new(config());
+        for (i, &byte) in data.iter().enumerate() {
+            chunker.push_buf(Bytes::copy_from_slice(&[byte]));
+            if chunker.pending_len() == 128 {
+                let checkpoint = chunker.checkpoint();
+                let pending = Bytes::copy_from_slice(&data[checkpoint.offset as usize..=i]);
+                return (checkpoint, pending);
+            }
+        }
+        panic!("no chunk of 128 bytes");
+    }
+
+    #[test]
+    fn test_restore_rejects_mismatches() {
+        let (checkpoint, pending) = checkpoint_after_data();
+        assert!(!pending.is_empty());
+
+        let other = ChunkConfig::new(32, 64, 256).unwrap();
+        assert!(matches!(
+            Chunker::restore(other, &checkpoint, pending.clone()),
+            Err(chunkrs::ChunkError::InvalidConfig { .. })
+        ));
+        assert!(matches!(
+            Chunker::restore(config(), &checkpoint, pending.slice(1..)),
+            Err(chunkrs::ChunkError::InvalidInput { .. })
+        ));
+        assert!(Chunker::restore(config(), &checkpoint, pending).is_ok());
+    }
+
+    #[test]
+    fn test_restore_rejects_inconsistent_checkpoint() {
+        let (checkpoint, pending) = checkpoint_after_data();
+
+        // Pending length that disagrees with the rolling state.
+        let mut short = checkpoint;
+        short.pending_len -= 1;
+        assert!(matches!(
+            Chunker::restore(config(), &short, pending.slice(1..)),
+            Err(chunkrs::ChunkError::InvalidInput { .. })
+        ));
+
+        // Rolling state past the maximum chunk size.
+        let mut long = checkpoint;
+        long.bytes_since_boundary = 1024;
+        long.pending_len = 1024;
+        assert!(matches!(
+            Chunker::restore(config(), &long, Bytes::from(vec![0u8; 1024])),
+            Err(chunkrs::ChunkError::InvalidInput { .. })
+        ));
+
+        // Right length, wrong bytes. Only the last 64 or so are still in the
+        // rolling hash.
+        let mut wrong = pending.to_vec();
+        wrong[127] ^= 1;
+        assert!(matches!(
+            Chunker::restore(config(), &checkpoint, Bytes::from(wrong.clone())),
+            Err(chunkrs::ChunkError::InvalidInput { .. })
+        ));
+        assert!(MetaChunker::restore(config(), &checkpoint, &wrong).is_err());
+
+        // Tampered rolling hash.
+        let mut tampered = checkpoint;
+        tampered.rolling_hash ^= 1;
+        assert!(Chunker::restore(config(), &tampered, pending).is_err());
+    }
+}
+
+// ============================================================================
+// Segmented Input
+// ============================================================================
+
+fn segmented_data() -> Vec<u8> {
+    (0..4000u32).map(|i| (i * 11 % 256) as u8).collect()
+}
+
+fn reference_chunks(config: ChunkConfig, data: &[u8]) -> Vec<chunkrs::Chunk> {
+    let mut chunker = Chunker::new(config);
+    let (chunks, _) = chunker.push(Bytes::copy_from_slice(data));
+    chunks.into_iter().chain(chunker.finish()).collect()
+}
+
+#[test]
+fn test_push_segments_matches_contiguous_push() {
+    use std::collections::VecDeque;
+
+    let config = ChunkConfig::new(16, 64, 256).unwrap();
+    let data = segmented_data();
+    let expected = reference_chunks(config, &data);
+
+    let segments: VecDeque<Bytes> = data.chunks(333).map(Bytes::copy_from_slice).collect();
+    let ranges: Vec<(usize, usize)> = segments
+        .iter()
+        .map(|s| (s.as_ptr() as usize, s.as_ptr() as usize + s.len()))
+        .collect();
+
+    let mut chunker = Chunker::new(config);
+    let mut chunks = chunker.push_segments(segments);
+    chunks.extend(chunker.finish());
+
+    assert_eq!(chunks.len(), expected.len());
+    let mut zero_copy = 0;
+    for (a, b) in chunks.iter().zip(&expected) {
+        assert_eq!(a.data, b.data);
+        assert_eq!(a.offset, b.offset);
+
+        // A chunk inside one segment must point into that segment.
+        let start = a.start() as usize;
+        if start / 333 == (start + a.len() - 1) / 333 {
+            let (lo, hi) = ranges[start / 333];
+            let ptr = a.data.as_ptr() as usize;
+            assert!(
+                ptr >= lo && ptr + a.len() <= hi,
+                "chunk at {} was copied",
+                start
+            );
+            zero_copy += 1;
+        }
+    }
+    assert!(zero_copy > 0);
+}
+
+#[test]
+fn test_push_buf_chain_matches_contiguous_push() {
+    use bytes::Buf;
+
+    let config = ChunkConfig::new(16, 64, 256).unwrap();
+    let data = segmented_data();
+    let expected = reference_chunks(config, &data);
+
+    let first = Bytes::copy_from_slice(&data[..1500]);
+    let second = Bytes::copy_from_slice(&data[1500..]);
+    let mut chunker = Chunker::new(config);
+    let mut chunks = chunker.push_buf(first.chain(second));
+    chunks.extend(chunker.finish());
+
+    let actual: Vec<_> = chunks.iter().map(|c| (c.offset, c.data.clone())).collect();
+    let wanted: Vec<_> = expected
+        .iter()
+        .map(|c| (c.offset, c.data.clone()))
+        .collect();
+    assert_eq!(actual, wanted);
+}
+
+// ============================================================================
+// Metadata-only Chunking
+// ============================================================================
+
+#[cfg(feature = "hash-blake3")]
+#[test]
+fn test_meta_chunker_matches_chunker() {
+    use chunkrs::ChunkMeta;
+
+    let config = ChunkConfig::new(16, 64, 256)
+        .unwrap()
+        .with_hash_config(HashConfig::enabled().with_weak_checksum(true));
+    let data: Vec<u8> = (0..5000u32).map(|i| (i * 13 % 256) as u8).collect();
+
+    let mut chunker = Chunker::new(config);
+    let (chunks, _) = chunker.push(Bytes::from(data.clone()));
+    let expected: Vec<ChunkMeta> = chunks
+        .iter()
+        .chain(chunker.finish().iter())
+        .map(ChunkMeta::from)
+        .collect();
+
+    for batch_size in [1, 7, 100, 5000] {
+        let mut chunker = MetaChunker::new(config);
+        let mut metas = Vec::new();
+        for batch in data.chunks(batch_size) {
+            metas.extend(chunker.push(batch));
+        }
+        let (final_meta, summary) = chunker.finish_with_summary();
+        metas.extend(final_meta);
+        assert_eq!(metas, expected, "batch size {}", batch_size);
+        assert_eq!(summary.len, 5000);
+        assert_eq!(summary.chunk_count, expected.len() as u64);
+    }
+}
+
+#[test]
+fn test_meta_chunker_summary_and_reset() {
+    let config = ChunkConfig::new(16, 64, 256).unwrap();
+    let data: Vec<u8> = (0..1000u32).map(|i| (i * 29 % 256) as u8).collect();
+
+    let mut chunker = MetaChunker::new(config);
+    let mut first = chunker.push(&data);
+    let (last, summary) = chunker.finish_with_summary();
+    first.extend(last);
+    assert_eq!(summary.len, 1000);
+    assert_eq!(summary.chunk_count, first.len() as u64);
+    assert_eq!(chunker.offset(), 0);
+
+    let mut second = chunker.push(&data);
+    second.extend(chunker.finish());
+    assert_eq!(second, first);
+}
+
+#[test]
+fn test_meta_chunker_summary_is_relative_to_base_offset() {
+    let config = ChunkConfig::new(16, 64, 256).unwrap();
+    let mut chunker = MetaChunker::with_offset(config, 1000);
+
+    chunker.push(&[3u8; 10]);
+    let (final_meta, summary) = chunker.finish_with_summary();
+    assert_eq!(final_meta.unwrap().offset, 1000);
+    assert_eq!(summary.len, 10);
+    assert_eq!(chunker.offset(), 1000);
+
+    chunker.push(&[3u8; 10]);
+    chunker.reset();
+    assert_eq!(chunker.offset(), 1000);
+    assert_eq!(chunker.pending_len(), 0);
+}
+
+// ============================================================================
+// Tokio Decoder
+// ============================================================================
+
+#[cfg(all(feature = "tokio", feature = "hash-blake3"))]
+mod tokio_tests {
+    use super::*;
+    use chunkrs::ChunkDecoder;
+    use futures::StreamExt;
+    use tokio_util::codec::FramedRead;
+
+    fn data() -> Vec<u8> {
+        (0..5000u32).map(|i| (i * 19 % 256) as u8).collect()
+    }
+
+    fn config() -> ChunkConfig {
+        ChunkConfig::new(16, 64, 256)
+            .unwrap()
+            .with_hash_config(HashConfig::enabled().with_stream_digest(true))
+    }
+
+    #[tokio::test]
+    async fn test_framed_read_matches_push() {
+        let mut chunker = Chunker::new(config());
+        let (chunks, _) = chunker.push(Bytes::from(data()));
+        let (final_chunk, expected_summary) = chunker.finish_with_summary();
+        let expected: Vec<_> = chunks.into_iter().chain(final_chunk).collect();
+
+        for capacity in [1, 100, 8192] {
+            let data = data();
+            let mut framed =
+                FramedRead::with_capacity(&data[..], ChunkDecoder::new(config()), capacity);
+            let mut chunks = Vec::new();
+            while let Some(chunk) = framed.next().await {
+                chunks.push(chunk.unwrap());
+            }
+
+            assert_eq!(chunks.len(), expected.len(), "capacity {}", capacity);
+            for (a, b) in chunks.iter().zip(&expected) {
+                assert_eq!(a.data, b.data);
+                assert_eq!(a.offset, b.offset);
+                assert_eq!(a.hash, b.hash);
+            }
+
+            // End of input ended the stream; the chunker continues after it.
+            let mut chunker = framed.into_parts().codec.into_inner();
+            assert_eq!(chunker.offset(), expected_summary.len);
+            assert_eq!(chunker.finish_with_summary().1.chunk_count, 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decoder_is_reusable_after_eof() {
+        let data = data();
+        let mut decoder = ChunkDecoder::new(config());
+        let mut streams = Vec::new();
+        for _ in 0..2 {
+            let mut framed = FramedRead::with_capacity(&data[..], decoder, 100);
+            let mut chunks = Vec::new();
+            while let Some(chunk) = framed.next().await {
+                chunks.push(chunk.unwrap());
+            }
+            streams.push(chunks);
+            decoder = framed.into_parts().codec;
+        }
+
+        // EOF ends the stream like `finish()`: the second one is chunked the
+        // same way and continues at the offset where the first ended.
+        assert_eq!(streams[0].len(), streams[1].len());
+        for (a, b) in streams[0].iter().zip(&streams[1]) {
+            assert_eq!(a.data, b.data);
+            assert_eq!(a.hash, b.hash);
+            assert_eq!(a.offset.unwrap() + 5000, b.offset.unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decoder_continues_pushed_chunker() {
+        let data = data();
+        let mut chunker = Chunker::new(config());
+        let mut whole = chunker.clone();
+        let mut expected = whole.push_buf(Bytes::from(data.clone()));
+        expected.extend(whole.finish());
+
+        let mut chunks = chunker.push_buf(Bytes::copy_from_slice(&data[..1000]));
+        let mut framed = FramedRead::new(&data[1000..], ChunkDecoder::from(chunker));
+        while let Some(chunk) = framed.next().await {
+            chunks.push(chunk.unwrap());
+        }
+
+        assert_eq!(chunks.len(), expected.len());
+        for (a, b) in chunks.iter().zip(&expected) {
+            assert_eq!((a.offset, &a.data, a.hash), (b.offset, &b.data, b.hash));
+        }
+    }
+
+    #[test]
+    fn test_mid_chunk_decoder_checkpoint_is_rejected() {
+        use tokio_util::codec::Decoder;
+
+        let mut decoder = ChunkDecoder::new(config());
+        let mut src = bytes::BytesMut::from(&[7u8; 10][..]);
+        assert!(decoder.decode(&mut src).unwrap().is_none());
+
+        let checkpoint = decoder.chunker().checkpoint();
+        assert!(Chunker::restore(config(), &checkpoint, Bytes::new()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_into_stream_empty_reader() {
+        let mut stream = Chunker::new(config()).into_stream(&b""[..]);
+        assert!(stream.next().await.is_none());
+    }
+}
+
+// ============================================================================
+// Base Offset
+// ============================================================================
+
+#[test]
+fn test_tail_from_boundary_matches_full_rechunk() {
+    // The rolling hash carried into a chunk is shifted out well before
+    // `min_size`, so the tail cannot tell it started from zero.
+    let config = ChunkConfig::new(256, 1024, 4096).unwrap();
+    let data: Vec<u8> = (0..40_000u32)
+        .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+        .collect();
+    let full = reference_chunks(config, &data);
+    assert!(full.len() > 4);
+
+    for skip in [1, full.len() / 2, full.len() - 1] {
+        let base = full[skip].offset.unwrap();
+        let mut chunker = Chunker::with_offset(config, base);
+        let (chunks, _) = chunker.push(Bytes::copy_from_slice(&data[base as usize..]));
+        let tail: Vec<_> = chunks.into_iter().chain(chunker.finish()).collect();
+
+        assert_eq!(tail.len(), full.len() - skip);
+        for (got, want) in tail.iter().zip(&full[skip..]) {
+            assert_eq!(got.offset, want.offset);
+            assert_eq!(got.data, want.data);
+            assert_eq!(got.hash, want.hash);
+        }
+    }
+}
+
+#[test]
+fn test_reset_to_starts_fresh_at_offset() {
+    let config = ChunkConfig::new(16, 64, 256).unwrap();
+    let data = segmented_data();
+    let full = reference_chunks(config, &data);
+    let base = full[2].offset.unwrap();
+
+    let mut chunker = Chunker::new(config);
+    chunker.push(Bytes::copy_from_slice(&data[..100]));
+    chunker.reset_to(base);
+    assert_eq!(chunker.offset(), base);
+    assert_eq!(chunker.pending_len(), 0);
+
+    let (chunks, _) = chunker.push(Bytes::copy_from_slice(&data[base as usize..]));
+    let tail: Vec<_> = chunks.into_iter().chain(chunker.finish()).collect();
+    assert_eq!(tail.len(), full.len() - 2);
+    assert_eq!(tail[0].offset, Some(base));
+    assert_eq!(tail.last().unwrap().data, full.last().unwrap().data);
+}
+
+#[test]
+fn test_summary_and_reset_are_relative_to_base_offset() {
+    let config = ChunkConfig::new(16, 64, 256).unwrap();
+    let mut chunker = Chunker::with_offset(config, 1000);
+
+    chunker.push_buf(Bytes::from(vec![3u8; 10]));
+    let (final_chunk, summary) = chunker.finish_with_summary();
+    assert_eq!(final_chunk.unwrap().offset, Some(1000));
+    assert_eq!(summary.len, 10);
+    assert_eq!(chunker.offset(), 1000);
+
+    chunker.push_buf(Bytes::from(vec![3u8; 10]));
+    chunker.reset();
+    assert_eq!(chunker.offset(), 1000);
+    assert_eq!(chunker.pending_len(), 0);
+}
+
+// ============================================================================
+// Cloning
+// ============================================================================
+
+#[test]
+fn test_clone_forks_two_continuations() {
+    let config = ChunkConfig::new(16, 64, 256).unwrap();
+    let data = segmented_data();
+    let mut edited = data.clone();
+    edited[3000..3100].fill(0xAB);
+
+    let mut chunker = Chunker::new(config);
+    let (prefix, _) = chunker.push(Bytes::copy_from_slice(&data[..2500]));
+    let mut fork = chunker.clone();
+    assert_eq!(fork.offset(), chunker.offset());
+    assert_eq!(fork.pending_len(), chunker.pending_len());
+
+    for (chunker, input) in [(&mut chunker, &data), (&mut fork, &edited)] {
+        let (chunks, _) = chunker.push(Bytes::copy_from_slice(&input[2500..]));
+        let all: Vec<_> = prefix
+            .iter()
+            .cloned()
+            .chain(chunks)
+            .chain(chunker.finish())
+            .collect();
+        let expected = reference_chunks(config, input);
+        assert_eq!(all.len(), expected.len());
+        for (got, want) in all.iter().zip(&expected) {
+            assert_eq!(got.offset, want.offset);
+            assert_eq!(got.data, want.data);
+            assert_eq!(got.hash, want.hash);
+        }
+    }
+}
+
+#[test]
+fn test_clone_mid_meta_chunk() {
+    let config = ChunkConfig::new(16, 64, 256).unwrap();
+    let data = segmented_data();
+    let mut expected = MetaChunker::new(config);
+    let mut metas = expected.push(&data);
+    metas.extend(expected.finish());
+
+    let mut chunker = MetaChunker::new(config);
+    let mut head = chunker.push(&data[..1001]);
+    let mut fork = chunker.clone();
+    drop(chunker);
+    head.extend(fork.push(&data[1001..]));
+    head.extend(fork.finish());
+    assert_eq!(head, metas);
+}
+
+#[cfg(feature = "hash-blake3")]
+#[test]
+fn test_clone_forks_stream_digest() {
+    let config = ChunkConfig::new(16, 64, 256)
+        .unwrap()
+        .with_hash_config(HashConfig::enabled().with_stream_digest(true));
+    let data = segmented_data();
+
+    let mut chunker = Chunker::new(config);
+    chunker.push(Bytes::copy_from_slice(&data[..1234]));
+    let mut fork = chunker.clone();
+    for chunker in [&mut chunker, &mut fork] {
+        chunker.push(Bytes::copy_from_slice(&data[1234..]));
+    }
+
+    let (_, summary) = chunker.finish_with_summary();
+    let (_, fork_summary) = fork.finish_with_summary();
+    assert!(summary.digest.is_some());
+    assert_eq!(summary, fork_summary);
+}
+
+#[test]
+fn test_clone_with_custom_hasher_mid_chunk() {
+    use chunkrs::{ChunkHash, ChunkHasher, HashAlgorithm};
+
+    #[derive(Debug, Clone, Default)]
+    struct SumHasher(u64);
+
+    impl ChunkHasher for SumHasher {
+        fn algorithm(&self) -> HashAlgorithm {
+            HashAlgorithm::Custom(0x0053_554d)
+        }
+        fn update(&mut self, data: &[u8]) {
+            self.0 = data
+                .iter()
+                .fold(self.0, |acc, &b| acc.wrapping_add(b as u64));
+        }
+        fn finalize(&self) -> ChunkHash {
+            let mut bytes = [0u8; 32];
+            bytes[..8].copy_from_slice(&self.0.to_le_bytes());
+            ChunkHash::with_algorithm(self.algorithm(), bytes)
+        }
+        fn reset(&mut self) {
+            self.0 = 0;
+        }
+        fn fork(&self) -> Box<dyn ChunkHasher> {
+            Box::new(self.clone())
+        }
+    }
+
+    let hash_config = HashConfig::enabled()
+        .with_hasher(HashAlgorithm::Custom(0x0053_554d), || {
+            Box::new(SumHasher::default())
+        })
+        .with_stream_digest(true);
+    let config = ChunkConfig::new(16, 64, 256)
+        .unwrap()
+        .with_hash_config(hash_config);
+    let data = segmented_data();
+
+    let mut expected = MetaChunker::new(config);
+    let mut metas = expected.push(&data);
+    metas.extend(expected.finish());
+    let (_, expected_summary) = expected.finish_with_summary();
+
+    let mut chunker = MetaChunker::new(config);
+    let mut head = chunker.push(&data[..1001]);
+    let mut fork = chunker.clone();
+    drop(chunker);
+    head.extend(fork.push(&data[1001..]));
+    head.extend(fork.finish());
+    assert_eq!(head, metas);
+    assert_eq!(fork.finish_with_summary().1, expected_summary);
+}
+
+// ============================================================================
+// Session API
+// ============================================================================
+
+#[test]
+fn test_session_matches_chunker() {
+    let config = ChunkConfig::new(16, 64, 256).unwrap();
+    let data = segmented_data();
+    let expected = reference_chunks(config, &data);
+
+    let mut session = ChunkSession::new(config);
+    let mut chunks = Vec::new();
+    for part in data.chunks(333) {
+        chunks.extend(session.push(Bytes::copy_from_slice(part)));
+    }
+    let (final_chunk, summary) = session.finish();
+    chunks.extend(final_chunk);
+
+    assert_eq!(chunks.len(), expected.len());
+    for (got, want) in chunks.iter().zip(&expected) {
+        assert_eq!(got.offset, want.offset);
+        assert_eq!(got.data, want.data);
+        assert_eq!(got.hash, want.hash);
+    }
+    assert_eq!(summary.len, data.len() as u64);
+    assert_eq!(summary.chunk_count, expected.len() as u64);
+}
+
+#[test]
+fn test_session_resumes_restored_chunker() {
+    let config = ChunkConfig::new(16, 64, 256).unwrap();
+    let data = segmented_data();
+    let expected = reference_chunks(config, &data);
+
+    let mut session = ChunkSession::new(config);
+    let mut chunks = session.push(Bytes::copy_from_slice(&data[..1500]));
+    let checkpoint = session.chunker().checkpoint();
+    drop(session);
+
+    let start = checkpoint.offset as usize;
+    let pending = Bytes::copy_from_slice(&data[start..1500]);
+    let mut session = ChunkSession::from(Chunker::restore(config, &checkpoint, pending).unwrap());
+    chunks.extend(session.push(Bytes::copy_from_slice(&data[1500..])));
+    chunks.extend(session.finish().0);
+
+    let offsets: Vec<_> = chunks.iter().map(|c| c.offset).collect();
+    let expected_offsets: Vec<_> = expected.iter().map(|c| c.offset).collect();
+    assert_eq!(offsets, expected_offsets);
+}
+
+#[test]
+fn test_push_after_finish_chunks_like_fresh_stream() {
+    let config = ChunkConfig::new(16, 64, 256).unwrap();
+    let data = segmented_data();
+    let expected = reference_chunks(config, &data);
+
+    let mut chunker = Chunker::new(config);
+    chunker.push(Bytes::from(vec![0x5Au8; 1000]));
+    let head = chunker.finish().unwrap();
+    let base = head.offset.unwrap() + head.len() as u64;
+
+    let mut chunks = chunker.push_buf(Bytes::copy_from_slice(&data));
+    let (final_chunk, summary) = chunker.finish_with_summary();
+    chunks.extend(final_chunk);
+    assert_eq!(chunks.len(), expected.len());
+    for (got, want) in chunks.iter().zip(&expected) {
+        assert_eq!(got.offset, want.offset.map(|offset| offset + base));
+        assert_eq!(got.data, want.data);
+    }
+
+    // The summary covers only the stream after `finish()`.
+    assert_eq!(summary.len, data.len() as u64);
+    assert_eq!(summary.chunk_count, expected.len() as u64);
 }
 
 // ============================================================================
@@ -503,6 +1426,7 @@ fn test_config_validation() {
     );
 }
 
+#[cfg(feature = "hash-blake3")]
 #[test]
 fn test_hash_config_consistency() {
     let data: Vec<u8> = (0..100).collect();